@@ -23,9 +23,31 @@ CONTRACT_ID=$(cat .soroban/contract-id-$NETWORK.txt)
 log_info "Contract ID: $CONTRACT_ID"
 log_info "Admin: $ADMIN_ADDRESS"
 log_info "Token: $TOKEN_NAME ($TOKEN_SYMBOL)"
-log_info "Mint Inicial: 10,000,000 BRZ"
+log_info "Mint Inicial: ${INITIAL_SUPPLY:-1000000000000000} (unidades, 7 decimais)"
 log_info "=========================================="
 
+# Configuração de deploy (InitConfig): todo o supply inicial vai para o admin
+INIT_CONFIG=$(cat <<EOF
+{
+  "name": "$TOKEN_NAME",
+  "symbol": "$TOKEN_SYMBOL",
+  "decimals": 7,
+  "max_supply": "${MAX_SUPPLY:-2100000000000000}",
+  "allocations": [
+    { "recipient": "$ADMIN_ADDRESS", "amount": "${INITIAL_SUPPLY:-1000000000000000}" }
+  ],
+  "compliance": {
+    "admin_country": "BR",
+    "admin_kyc_level": 3,
+    "blocked_countries": [],
+    "travel_rule_threshold": "0"
+  },
+  "compliance_officer": null,
+  "reserve_attester": null
+}
+EOF
+)
+
 # Inicializar contrato
 log_info "Inicializando contrato..."
 
@@ -36,8 +58,7 @@ soroban contract invoke \
     -- \
     initialize \
     --admin $ADMIN_ADDRESS \
-    --name "$TOKEN_NAME" \
-    --symbol "$TOKEN_SYMBOL"
+    --config "$INIT_CONFIG"
 
 log_success "Contrato inicializado com sucesso!"
 log_info "=========================================="
@@ -0,0 +1,62 @@
+#!/bin/bash
+
+# ============================================================================
+# UPGRADE DO CONTRATO BRAZA TOKEN (update_code)
+# ============================================================================
+
+set -e
+source scripts/load-env.sh
+
+# Layout de storage esperado pelo WASM atual (storage::STORAGE_VERSION)
+EXPECTED_STORAGE_VERSION=2
+
+log_info "=========================================="
+log_info "  UPGRADE DO CONTRATO"
+log_info "=========================================="
+
+if [ ! -f ".soroban/braza_token.wasm" ]; then
+    log_error "WASM não encontrado! Execute primeiro: ./scripts/build.sh"
+    exit 1
+fi
+
+if [ ! -f ".soroban/contract-id-$NETWORK.txt" ]; then
+    log_error "Contract ID não encontrado!"
+    log_info "Execute primeiro: ./scripts/deploy.sh"
+    exit 1
+fi
+
+CONTRACT_ID=$(cat .soroban/contract-id-$NETWORK.txt)
+log_info "Contract ID: $CONTRACT_ID"
+
+# Contratos do layout anterior não têm get_storage_version: sem migração,
+# os schedules gravados não desserializam no WASM novo.
+STORAGE_VERSION=$(soroban contract invoke \
+    --id $CONTRACT_ID \
+    --source-account $ADMIN_ADDRESS \
+    --network $NETWORK \
+    -- \
+    get_storage_version 2>/dev/null || echo "0")
+
+if [ "$STORAGE_VERSION" != "$EXPECTED_STORAGE_VERSION" ]; then
+    log_error "Layout de storage $STORAGE_VERSION incompatível (esperado: $EXPECTED_STORAGE_VERSION)"
+    log_info "Upgrade não suportado: faça um deploy novo (./scripts/deploy-all.sh)"
+    exit 1
+fi
+
+log_info "Instalando WASM na rede $NETWORK..."
+WASM_HASH=$(soroban contract install \
+    --wasm .soroban/braza_token.wasm \
+    --source $ADMIN_SECRET_KEY \
+    --network $NETWORK)
+
+soroban contract invoke \
+    --id $CONTRACT_ID \
+    --source $ADMIN_SECRET_KEY \
+    --network $NETWORK \
+    -- \
+    update_code \
+    --new_wasm_hash $WASM_HASH
+
+log_success "Upgrade concluído!"
+log_info "WASM Hash: $WASM_HASH"
+log_info "=========================================="
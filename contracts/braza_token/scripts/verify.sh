@@ -123,9 +123,9 @@ else
 fi
 
 # Verificar supply inicial
-EXPECTED_INITIAL_SUPPLY=100000000000000
+EXPECTED_INITIAL_SUPPLY=${INITIAL_SUPPLY:-1000000000000000}
 if [ "$TOTAL_SUPPLY_CLEAN" = "$EXPECTED_INITIAL_SUPPLY" ]; then
-    log_success "Supply inicial correto: 10,000,000 BRZ"
+    log_success "Supply inicial correto: $EXPECTED_INITIAL_SUPPLY bra"
 else
     log_warning "Supply inicial: $TOTAL_SUPPLY_CLEAN (esperado: $EXPECTED_INITIAL_SUPPLY)"
 fi
@@ -1,5 +1,6 @@
 use crate::storage;
-use crate::types::BrazaError;
+use crate::types::{BrazaError, SupplyCapProposal};
+use crate::validation;
 use soroban_sdk::{symbol_short, token, Address, Env}; // Importa 'token' do SDK
 
 // ============================================================================
@@ -78,9 +79,125 @@ pub fn force_burn(env: &Env, from: &Address, amount: i128) -> Result<(), BrazaEr
 
     storage::set_balance(env, from, new_from);
     storage::set_total_supply(env, new_supply);
+    storage::add_total_burned(env, amount);
+    storage::add_force_burned(env, amount);
 
     env.events()
         .publish((symbol_short!("force_brn"), from), amount);
 
     Ok(())
 }
+
+// ============================================================================
+// TETO DE SUPPLY - REDUÇÃO IMEDIATA, AUMENTO COM TIMELOCK
+// ============================================================================
+
+/// Reduz o teto na hora (nunca abaixo do supply atual).
+pub fn lower_max_supply(env: &Env, admin: &Address, new_max: i128) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if new_max >= storage::get_max_supply(env) {
+        return Err(BrazaError::InvalidAmount);
+    }
+    if new_max < storage::get_total_supply(env) {
+        return Err(BrazaError::MaxSupplyExceeded);
+    }
+
+    storage::set_max_supply(env, new_max);
+    storage::remove_max_supply_proposal(env);
+
+    env.events().publish((symbol_short!("max_sup"),), new_max);
+    Ok(())
+}
+
+/// Define o endereço de governança (multisig), distinto do admin.
+/// O admin só faz a primeira definição; depois só a própria governança troca.
+pub fn set_governance(env: &Env, caller: &Address, governance: &Address) -> Result<(), BrazaError> {
+    caller.require_auth();
+    storage::bump_critical_storage(env);
+
+    match storage::get_governance(env) {
+        Some(current) if &current != caller => return Err(BrazaError::Unauthorized),
+        Some(_) => {}
+        None => validation::require_admin(env, caller)?,
+    }
+    if governance == &storage::get_admin(env) {
+        return Err(BrazaError::Unauthorized);
+    }
+
+    storage::set_governance(env, governance);
+
+    env.events()
+        .publish((symbol_short!("gov_set"), caller), governance.clone());
+    Ok(())
+}
+
+/// Governança configurada e distinta do admin atual
+fn require_governance(env: &Env, caller: &Address) -> Result<(), BrazaError> {
+    let governance = storage::get_governance(env).ok_or(BrazaError::GovernanceNotSet)?;
+    if &governance != caller || caller == &storage::get_admin(env) {
+        return Err(BrazaError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Propõe aumento do teto; a governança executa após `MAX_SUPPLY_TIMELOCK_LEDGERS`.
+pub fn propose_max_supply_increase(
+    env: &Env,
+    admin: &Address,
+    new_max: i128,
+) -> Result<SupplyCapProposal, BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if new_max <= storage::get_max_supply(env) {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    let proposal = SupplyCapProposal {
+        new_max_supply: new_max,
+        eta_ledger: env
+            .ledger()
+            .sequence()
+            .saturating_add(storage::MAX_SUPPLY_TIMELOCK_LEDGERS),
+    };
+    storage::set_max_supply_proposal(env, &proposal);
+
+    env.events()
+        .publish((symbol_short!("max_prop"),), (new_max, proposal.eta_ledger));
+    Ok(proposal)
+}
+
+pub fn execute_max_supply_increase(env: &Env, governance: &Address) -> Result<i128, BrazaError> {
+    governance.require_auth();
+    storage::bump_critical_storage(env);
+    require_governance(env, governance)?;
+
+    let proposal =
+        storage::get_max_supply_proposal(env).ok_or(BrazaError::NoPendingSupplyChange)?;
+    if env.ledger().sequence() < proposal.eta_ledger {
+        return Err(BrazaError::TimelockNotExpired);
+    }
+
+    storage::set_max_supply(env, proposal.new_max_supply);
+    storage::remove_max_supply_proposal(env);
+
+    env.events()
+        .publish((symbol_short!("max_sup"),), proposal.new_max_supply);
+    Ok(proposal.new_max_supply)
+}
+
+pub fn cancel_max_supply_increase(env: &Env, admin: &Address) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    storage::get_max_supply_proposal(env).ok_or(BrazaError::NoPendingSupplyChange)?;
+    storage::remove_max_supply_proposal(env);
+
+    env.events().publish((symbol_short!("max_cncl"),), ());
+    Ok(())
+}
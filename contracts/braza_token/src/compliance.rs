@@ -1,5 +1,5 @@
 use crate::storage;
-use crate::types::BrazaError;
+use crate::types::{BrazaError, CorridorRule, VelocityRule};
 use crate::validation;
 use soroban_sdk::{symbol_short, Address, Env, String};
 
@@ -197,6 +197,123 @@ pub fn require_country_allowed(env: &Env, user: &Address) -> Result<(), BrazaErr
     }
 }
 
+// ============================================================================
+// CORREDORES (ORIGEM -> DESTINO)
+// ============================================================================
+
+/// Código coringa: casa com qualquer país sem regra específica
+pub const ANY_COUNTRY: &str = "*";
+
+pub fn set_corridor_rule(
+    env: &Env,
+    admin: &Address,
+    from_code: String,
+    to_code: String,
+    rule: &CorridorRule,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if from_code.is_empty() || to_code.is_empty() || rule.min_kyc > 3 {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    let key = (
+        symbol_short!("corridor"),
+        from_code.clone(),
+        to_code.clone(),
+    );
+    env.storage().persistent().set(&key, rule);
+
+    env.events().publish(
+        (symbol_short!("corr_set"), from_code, to_code),
+        (rule.blocked, rule.min_kyc),
+    );
+
+    Ok(())
+}
+
+pub fn remove_corridor_rule(
+    env: &Env,
+    admin: &Address,
+    from_code: String,
+    to_code: String,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    let key = (
+        symbol_short!("corridor"),
+        from_code.clone(),
+        to_code.clone(),
+    );
+    env.storage().persistent().remove(&key);
+
+    env.events()
+        .publish((symbol_short!("corr_del"), from_code, to_code), true);
+
+    Ok(())
+}
+
+fn get_exact_corridor_rule(
+    env: &Env,
+    from_code: &String,
+    to_code: &String,
+) -> Option<CorridorRule> {
+    let key = (
+        symbol_short!("corridor"),
+        from_code.clone(),
+        to_code.clone(),
+    );
+    env.storage().persistent().get(&key)
+}
+
+/// Regra efetiva para o par. Ordem: exata, origem->*, *->destino, padrão.
+/// Sem regra explícita só BR->BR é livre; qualquer par com outro país é negado.
+pub fn get_corridor_rule(env: &Env, from_code: String, to_code: String) -> CorridorRule {
+    storage::bump_critical_storage(env);
+    let any = String::from_str(env, ANY_COUNTRY);
+    let br = String::from_str(env, "BR");
+    let domestic = from_code == br && to_code == br;
+
+    get_exact_corridor_rule(env, &from_code, &to_code)
+        .or_else(|| get_exact_corridor_rule(env, &from_code, &any))
+        .or_else(|| get_exact_corridor_rule(env, &any, &to_code))
+        .unwrap_or(CorridorRule {
+            blocked: !domestic,
+            min_kyc: 0,
+        })
+}
+
+pub fn is_corridor_allowed(env: &Env, from_code: String, to_code: String) -> bool {
+    !get_corridor_rule(env, from_code, to_code).blocked
+}
+
+/// Par liberado entre os países de `from` e `to` (regra explícita ou BR->BR)
+pub fn is_pair_corridor_allowed(env: &Env, from: &Address, to: &Address) -> bool {
+    match (get_country_code(env, from), get_country_code(env, to)) {
+        (Some(f), Some(t)) => is_corridor_allowed(env, f, t),
+        _ => false,
+    }
+}
+
+/// Avalia o corredor entre os países de `from` e `to`.
+pub fn require_corridor_allowed(env: &Env, from: &Address, to: &Address) -> Result<(), BrazaError> {
+    let from_code = get_country_code(env, from).ok_or(BrazaError::Unauthorized)?;
+    let to_code = get_country_code(env, to).ok_or(BrazaError::Unauthorized)?;
+
+    let rule = get_corridor_rule(env, from_code, to_code);
+    if rule.blocked {
+        return Err(BrazaError::CorridorBlocked);
+    }
+    if rule.min_kyc > 0 && get_kyc_level(env, from) < rule.min_kyc {
+        return Err(BrazaError::CorridorKycRequired);
+    }
+    Ok(())
+}
+
 // ============================================================================
 // AML / RISCO
 // ============================================================================
@@ -328,6 +445,25 @@ pub fn check_and_update_daily_volume(
     Ok(())
 }
 
+/// Estorna volume diário contabilizado em `counted_ledger`, se ainda for o mesmo dia.
+pub fn refund_daily_volume(env: &Env, user: &Address, amount: i128, counted_ledger: u32) {
+    let today = env.ledger().sequence() / LEDGERS_PER_DAY;
+    if counted_ledger / LEDGERS_PER_DAY != today {
+        return;
+    }
+
+    let day_key = (symbol_short!("vol_day"), user);
+    let last_day: u32 = env.storage().persistent().get(&day_key).unwrap_or(0);
+    if last_day != today {
+        return;
+    }
+
+    let amt_key = (symbol_short!("vol_amt"), user);
+    let volume: i128 = env.storage().persistent().get(&amt_key).unwrap_or(0);
+    let refunded = volume.saturating_sub(amount).max(0);
+    env.storage().persistent().set(&amt_key, &refunded);
+}
+
 pub fn get_daily_volume(env: &Env, user: &Address) -> i128 {
     storage::bump_critical_storage(env);
 
@@ -345,6 +481,239 @@ pub fn get_daily_volume(env: &Env, user: &Address) -> i128 {
     }
 }
 
+// ============================================================================
+// LIMITES DE RECEBIMENTO (INFLOW) E VELOCIDADE
+// ============================================================================
+
+pub fn set_daily_inflow_limit(
+    env: &Env,
+    admin: &Address,
+    user: &Address,
+    limit: i128,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if limit <= 0 {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    let key = (symbol_short!("in_lim"), user);
+    env.storage().persistent().set(&key, &limit);
+
+    env.events()
+        .publish((symbol_short!("inlim_set"), user), limit);
+
+    Ok(())
+}
+
+pub fn get_daily_inflow_limit(env: &Env, user: &Address) -> i128 {
+    storage::bump_critical_storage(env);
+    let key = (symbol_short!("in_lim"), user);
+    env.storage().persistent().get(&key).unwrap_or(i128::MAX)
+}
+
+pub fn set_velocity_rule(
+    env: &Env,
+    admin: &Address,
+    max_senders: u32,
+    block: bool,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    let rule = VelocityRule { max_senders, block };
+    env.storage()
+        .persistent()
+        .set(&symbol_short!("vel_rule"), &rule);
+
+    env.events()
+        .publish((symbol_short!("vel_set"),), (max_senders, block));
+
+    Ok(())
+}
+
+pub fn get_velocity_rule(env: &Env) -> VelocityRule {
+    storage::bump_critical_storage(env);
+    env.storage()
+        .persistent()
+        .get(&symbol_short!("vel_rule"))
+        .unwrap_or(VelocityRule {
+            max_senders: 0,
+            block: false,
+        })
+}
+
+/// Contabiliza o recebimento do dia para `to` (volume e remetentes distintos).
+/// Mesmo esquema de `check_and_update_daily_volume`: chave com o dia + acumulador.
+pub fn check_and_update_daily_inflow(
+    env: &Env,
+    to: &Address,
+    from: &Address,
+    amount: i128,
+) -> Result<(), BrazaError> {
+    storage::bump_critical_storage(env);
+    validation::require_positive_amount(amount)?;
+
+    let today = env.ledger().sequence() / LEDGERS_PER_DAY;
+
+    let day_key = (symbol_short!("in_day"), to);
+    let amt_key = (symbol_short!("in_amt"), to);
+    let cnt_key = (symbol_short!("in_snd"), to);
+
+    let last_day: u32 = env.storage().persistent().get(&day_key).unwrap_or(0);
+    let same_day = last_day == today;
+
+    let mut volume: i128 = if same_day {
+        env.storage().persistent().get(&amt_key).unwrap_or(0)
+    } else {
+        0
+    };
+    volume = volume
+        .checked_add(amount)
+        .ok_or(BrazaError::InvalidAmount)?;
+
+    if volume > get_daily_inflow_limit(env, to) {
+        return Err(BrazaError::InflowLimitExceeded);
+    }
+
+    let mut senders: u32 = if same_day {
+        env.storage().persistent().get(&cnt_key).unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Remetente já visto hoje não conta de novo. Marcação em storage
+    // temporário: expira sozinha no fim do dia, sem acumular entradas.
+    let seen_key = (symbol_short!("in_seen"), to, from);
+    let seen_day: Option<u32> = env.storage().temporary().get(&seen_key);
+    if seen_day != Some(today) {
+        senders = senders.saturating_add(1);
+
+        let rule = get_velocity_rule(env);
+        if rule.max_senders > 0 && senders > rule.max_senders {
+            if rule.block {
+                return Err(BrazaError::VelocityLimitExceeded);
+            }
+            env.events()
+                .publish((symbol_short!("vel_flag"), to), senders);
+        }
+
+        let rest_of_day = LEDGERS_PER_DAY - env.ledger().sequence() % LEDGERS_PER_DAY;
+        env.storage().temporary().set(&seen_key, &today);
+        env.storage()
+            .temporary()
+            .extend_ttl(&seen_key, rest_of_day, rest_of_day);
+    }
+
+    env.storage().persistent().set(&day_key, &today);
+    env.storage().persistent().set(&amt_key, &volume);
+    env.storage().persistent().set(&cnt_key, &senders);
+
+    Ok(())
+}
+
+/// Consulta sem efeitos colaterais: o recebimento estouraria o limite diário?
+pub fn would_exceed_daily_inflow(env: &Env, to: &Address, amount: i128) -> bool {
+    match get_daily_inflow(env, to).checked_add(amount) {
+        Some(volume) => volume > get_daily_inflow_limit(env, to),
+        None => true,
+    }
+}
+
+/// Consulta sem efeitos colaterais: um novo remetente seria bloqueado pela regra de velocidade?
+pub fn would_exceed_velocity(env: &Env, to: &Address, from: &Address) -> bool {
+    let rule = get_velocity_rule(env);
+    if rule.max_senders == 0 || !rule.block {
+        return false;
+    }
+
+    let today = env.ledger().sequence() / LEDGERS_PER_DAY;
+    let seen_key = (symbol_short!("in_seen"), to, from);
+    let seen_day: Option<u32> = env.storage().temporary().get(&seen_key);
+    if seen_day == Some(today) {
+        return false;
+    }
+
+    get_daily_sender_count(env, to).saturating_add(1) > rule.max_senders
+}
+
+pub fn get_daily_inflow(env: &Env, user: &Address) -> i128 {
+    storage::bump_critical_storage(env);
+
+    let today = env.ledger().sequence() / LEDGERS_PER_DAY;
+    let day_key = (symbol_short!("in_day"), user);
+    let last_day: u32 = env.storage().persistent().get(&day_key).unwrap_or(0);
+
+    if last_day == today {
+        let amt_key = (symbol_short!("in_amt"), user);
+        env.storage().persistent().get(&amt_key).unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+pub fn get_daily_sender_count(env: &Env, user: &Address) -> u32 {
+    storage::bump_critical_storage(env);
+
+    let today = env.ledger().sequence() / LEDGERS_PER_DAY;
+    let day_key = (symbol_short!("in_day"), user);
+    let last_day: u32 = env.storage().persistent().get(&day_key).unwrap_or(0);
+
+    if last_day == today {
+        let cnt_key = (symbol_short!("in_snd"), user);
+        env.storage().persistent().get(&cnt_key).unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+// ============================================================================
+// TRAVEL RULE
+// ============================================================================
+
+pub fn set_travel_rule_threshold(
+    env: &Env,
+    admin: &Address,
+    threshold: i128,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if threshold < 0 {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&symbol_short!("tr_thr"), &threshold);
+
+    env.events()
+        .publish((symbol_short!("trthr_set"),), threshold);
+
+    Ok(())
+}
+
+/// Valor acima do qual o memo de travel rule é obrigatório (0 = desativado)
+pub fn get_travel_rule_threshold(env: &Env) -> i128 {
+    storage::bump_critical_storage(env);
+    env.storage()
+        .persistent()
+        .get(&symbol_short!("tr_thr"))
+        .unwrap_or(0)
+}
+
+pub fn require_travel_rule_memo(env: &Env, amount: i128, has_memo: bool) -> Result<(), BrazaError> {
+    let threshold = get_travel_rule_threshold(env);
+    if threshold > 0 && amount > threshold && !has_memo {
+        return Err(BrazaError::TravelRuleMemoRequired);
+    }
+    Ok(())
+}
+
 pub fn is_fully_compliant(env: &Env, user: &Address) -> bool {
     storage::bump_critical_storage(env);
 
@@ -377,11 +746,15 @@ pub fn is_fully_compliant(env: &Env, user: &Address) -> bool {
 pub mod test_utils {
     // Re-exportar funções internas APENAS para testes
     pub use super::{
-        add_blocked_country, check_and_update_daily_volume, get_country_code, get_daily_limit,
-        get_daily_volume, get_kyc_level, get_risk_score, is_accredited_investor,
-        is_country_blocked, is_fully_compliant, remove_blocked_country, require_acceptable_risk,
-        require_country_allowed, require_kyc_level, set_accredited_investor, set_country_code,
-        set_daily_limit, set_kyc_level, set_risk_score,
+        add_blocked_country, check_and_update_daily_inflow, check_and_update_daily_volume,
+        get_corridor_rule, get_country_code, get_daily_inflow, get_daily_inflow_limit,
+        get_daily_limit, get_daily_sender_count, get_daily_volume, get_kyc_level, get_risk_score,
+        get_travel_rule_threshold, get_velocity_rule, is_accredited_investor, is_corridor_allowed,
+        is_country_blocked, is_fully_compliant, remove_blocked_country, remove_corridor_rule,
+        require_acceptable_risk, require_corridor_allowed, require_country_allowed,
+        require_kyc_level, require_travel_rule_memo, set_accredited_investor, set_corridor_rule,
+        set_country_code, set_daily_inflow_limit, set_daily_limit, set_kyc_level, set_risk_score,
+        set_travel_rule_threshold, set_velocity_rule,
     };
 }
 #[cfg(test)]
@@ -1,4 +1,6 @@
-use crate::compliance::{get_country_code, get_kyc_level, get_risk_score};
+use crate::compliance::{
+    get_country_code, get_kyc_level, get_risk_score, is_country_blocked, is_pair_corridor_allowed,
+};
 use crate::storage;
 use crate::BrazaError;
 use soroban_sdk::{Address, Env, String};
@@ -22,11 +24,33 @@ pub struct ComplianceCache {
 // VALIDAÇÃO COM CACHE MELHORADO
 // ============================================================================
 
+/// Valida as duas pontas; fora do BR só passa se o corredor do par permitir.
+pub fn validate_pair_with_cache(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    min_kyc: u32,
+    max_risk: u32,
+) -> Result<(), BrazaError> {
+    let domestic = validate_with_cache(env, from, min_kyc, max_risk, false)
+        .and_then(|_| validate_with_cache(env, to, min_kyc, max_risk, false));
+
+    // Regras de corredor só são lidas quando alguma ponta não passa no padrão BR
+    match domestic {
+        Err(BrazaError::Unauthorized) if is_pair_corridor_allowed(env, from, to) => {
+            validate_with_cache(env, from, min_kyc, max_risk, true)?;
+            validate_with_cache(env, to, min_kyc, max_risk, true)
+        }
+        res => res,
+    }
+}
+
 pub fn validate_with_cache(
     env: &Env,
     user: &Address,
     min_kyc: u32,
     max_risk: u32,
+    corridor_allowed: bool,
 ) -> Result<(), BrazaError> {
     // ✅ PASSO 1: Tentar ler do cache persistente
     let current_ledger = env.ledger().sequence();
@@ -43,6 +67,7 @@ pub fn validate_with_cache(
                 &cache.country_code,
                 min_kyc,
                 max_risk,
+                corridor_allowed,
             );
         }
     }
@@ -57,13 +82,23 @@ pub fn validate_with_cache(
     write_cache(env, user, kyc, risk, blacklisted, &country, current_ledger);
 
     // ✅ Validar valores
-    validate_cached_values(env, kyc, risk, blacklisted, &country, min_kyc, max_risk)
+    validate_cached_values(
+        env,
+        kyc,
+        risk,
+        blacklisted,
+        &country,
+        min_kyc,
+        max_risk,
+        corridor_allowed,
+    )
 }
 
 // ============================================================================
 // FUNÇÕES AUXILIARES
 // ============================================================================
 
+#[allow(clippy::too_many_arguments)]
 fn validate_cached_values(
     env: &Env,
     kyc: u32,
@@ -72,6 +107,7 @@ fn validate_cached_values(
     country: &Option<String>,
     min_kyc: u32,
     max_risk: u32,
+    corridor_allowed: bool,
 ) -> Result<(), BrazaError> {
     // ✅ Validar valores REAIS
     if kyc < min_kyc {
@@ -85,10 +121,12 @@ fn validate_cached_values(
     }
 
     // ✅ Verificar se país é permitido (usando o MESMO env!)
+    // Fora do BR só com corredor explicitamente liberado para o par
     match country {
         Some(c) => {
             let br = String::from_str(env, "BR");
-            if c != &br {
+            if c != &br && (!corridor_allowed || c.is_empty() || is_country_blocked(env, c.clone()))
+            {
                 return Err(BrazaError::Unauthorized);
             }
         }
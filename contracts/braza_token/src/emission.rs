@@ -0,0 +1,221 @@
+use crate::events;
+use crate::minting;
+use crate::reserve;
+use crate::storage;
+use crate::types::{BrazaError, EmissionConfig};
+use crate::validation;
+use soroban_sdk::{symbol_short, Address, Env, Vec};
+
+// ============================================================================
+// EMISSÃO PROGRAMADA (HALVINGS)
+// ============================================================================
+//
+// Taxa por ledger que cai pela metade a cada `halving_interval` ledgers.
+// Qualquer um pode chamar `emit()`: o valor devido desde a última emissão é
+// cunhado e dividido entre os destinatários configurados.
+//
+// Trocar a config nunca é retroativo: o acumulado sob a config anterior vira
+// um saldo fixo (`em_owed`) e a nova taxa só vale a partir da troca.
+//
+// A emissão conta nos tetos de mint por janela (minting.rs), com o próprio
+// contrato como minter; o que passar do teto fica no saldo pendente.
+
+const BPS_DENOMINATOR: u32 = 10_000;
+
+pub fn get_emission_config(env: &Env) -> Option<EmissionConfig> {
+    env.storage().instance().get(&symbol_short!("em_cfg"))
+}
+
+pub fn set_emission_config(
+    env: &Env,
+    admin: &Address,
+    config: &EmissionConfig,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if config.initial_rate <= 0 || config.halving_interval == 0 || config.recipients.is_empty() {
+        return Err(BrazaError::InvalidAmount);
+    }
+    let mut total_bps: u32 = 0;
+    for r in config.recipients.iter() {
+        if r.share_bps == 0 {
+            return Err(BrazaError::InvalidAmount);
+        }
+        total_bps = total_bps.saturating_add(r.share_bps);
+    }
+    if total_bps != BPS_DENOMINATOR {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    // Fecha o período da config anterior: o devido fica congelado no saldo
+    // pendente e a nova config só acumula a partir de agora
+    let now = env.ledger().sequence();
+    if let (Some(old), Some(last)) = (get_emission_config(env), get_last_emission_ledger(env)) {
+        let owed = get_emission_backlog(env).saturating_add(accrued_between(&old, last, now));
+        set_emission_backlog(env, owed);
+    }
+    set_last_emission_ledger(env, config.start_ledger.max(now));
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("em_cfg"), config);
+
+    env.events().publish(
+        (symbol_short!("em_cfg"), admin),
+        (config.initial_rate, config.halving_interval),
+    );
+    Ok(())
+}
+
+pub fn get_last_emission_ledger(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&symbol_short!("em_last"))
+}
+
+fn set_last_emission_ledger(env: &Env, ledger: u32) {
+    env.storage()
+        .instance()
+        .set(&symbol_short!("em_last"), &ledger);
+}
+
+/// Devido ainda não cunhado de períodos já fechados (config anterior).
+pub fn get_emission_backlog(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("em_owed"))
+        .unwrap_or(0)
+}
+
+fn set_emission_backlog(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&symbol_short!("em_owed"), &amount);
+}
+
+pub fn get_total_emitted(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("em_total"))
+        .unwrap_or(0)
+}
+
+/// Época em `ledger` (0 antes do início).
+pub fn epoch_at(config: &EmissionConfig, ledger: u32) -> u32 {
+    ledger.saturating_sub(config.start_ledger) / config.halving_interval
+}
+
+/// Taxa por ledger em uma época: `initial_rate >> epoch`.
+pub fn rate_for_epoch(config: &EmissionConfig, epoch: u32) -> i128 {
+    if epoch >= 127 {
+        return 0;
+    }
+    config.initial_rate >> epoch
+}
+
+/// Primeiro ledger da próxima época.
+pub fn next_halving_ledger(config: &EmissionConfig, ledger: u32) -> u32 {
+    let epoch = epoch_at(config, ledger);
+    config.start_ledger.saturating_add(
+        epoch
+            .saturating_add(1)
+            .saturating_mul(config.halving_interval),
+    )
+}
+
+/// Valor devido entre `from` e `to`, integrando época a época.
+fn accrued_between(config: &EmissionConfig, from: u32, to: u32) -> i128 {
+    let mut due: i128 = 0;
+    let mut cursor = from.max(config.start_ledger);
+
+    while cursor < to {
+        let rate = rate_for_epoch(config, epoch_at(config, cursor));
+        if rate == 0 {
+            break;
+        }
+        let segment_end = next_halving_ledger(config, cursor).min(to);
+        due = due.saturating_add(rate.saturating_mul((segment_end - cursor) as i128));
+        cursor = segment_end;
+    }
+    due
+}
+
+/// Total devido: saldo congelado + acumulado na config atual.
+fn owed_emission(env: &Env) -> i128 {
+    let backlog = get_emission_backlog(env);
+    match (get_emission_config(env), get_last_emission_ledger(env)) {
+        (Some(config), Some(last)) => {
+            backlog.saturating_add(accrued_between(&config, last, env.ledger().sequence()))
+        }
+        _ => backlog,
+    }
+}
+
+/// Valor que `emit()` cunharia agora (limitado pelo teto de supply e de mint).
+pub fn pending_emission(env: &Env) -> i128 {
+    let due = owed_emission(env);
+    let headroom = storage::get_max_supply(env).saturating_sub(storage::get_total_supply(env));
+    let allowance = minting::remaining_window_allowance(env).min(
+        minting::remaining_minter_allowance(env, &env.current_contract_address()),
+    );
+    due.min(headroom).min(allowance).max(0)
+}
+
+/// Cunha o valor devido e divide entre os destinatários. Retorna o total emitido.
+pub fn emit(env: &Env) -> Result<i128, BrazaError> {
+    let config = get_emission_config(env).ok_or(BrazaError::EmissionNotConfigured)?;
+    validation::require_not_paused(env)?;
+
+    let owed = owed_emission(env);
+    let due = pending_emission(env);
+    let now = env.ledger().sequence();
+    set_last_emission_ledger(env, now);
+    set_emission_backlog(env, owed - due);
+
+    if due == 0 {
+        return Ok(0);
+    }
+    reserve::require_within_reserve(env, due)?;
+    minting::check_and_record_mint(env, &env.current_contract_address(), due)?;
+
+    // Arredondamento fica com o primeiro destinatário
+    let mut shares: Vec<i128> = Vec::new(env);
+    let mut distributed: i128 = 0;
+    for r in config.recipients.iter() {
+        validation::require_not_blacklisted(env, &r.recipient)?;
+        let share = due * r.share_bps as i128 / BPS_DENOMINATOR as i128;
+        shares.push_back(share);
+        distributed += share;
+    }
+    let dust = due - distributed;
+
+    for (i, r) in config.recipients.iter().enumerate() {
+        let mut share = shares.get(i as u32).unwrap_or(0);
+        if i == 0 {
+            share += dust;
+        }
+        if share == 0 {
+            continue;
+        }
+        let bal = storage::get_balance(env, &r.recipient);
+        let new_bal = bal.checked_add(share).ok_or(BrazaError::OverflowError)?;
+        storage::set_balance(env, &r.recipient, new_bal);
+        events::emit_mint(env, &env.current_contract_address(), &r.recipient, share);
+    }
+
+    let supply = storage::get_total_supply(env);
+    let new_supply = supply
+        .checked_add(due)
+        .ok_or(BrazaError::MaxSupplyExceeded)?;
+    storage::set_total_supply(env, new_supply);
+    storage::add_total_minted(env, due);
+
+    let total = get_total_emitted(env).saturating_add(due);
+    env.storage()
+        .instance()
+        .set(&symbol_short!("em_total"), &total);
+
+    env.events()
+        .publish((symbol_short!("emission"), epoch_at(&config, now)), due);
+    Ok(due)
+}
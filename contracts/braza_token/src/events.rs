@@ -1,3 +1,4 @@
+use crate::types::TravelRuleMemo;
 use soroban_sdk::{symbol_short, Address, Env};
 
 // ============================================================================
@@ -10,9 +11,23 @@ pub fn emit_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
         .publish((symbol_short!("transfer"), from, to), amount);
 }
 
-// Mint padrão SEP‑0041
-pub fn emit_mint(env: &Env, to: &Address, amount: i128) {
-    env.events().publish((symbol_short!("mint"), to), amount);
+// Transferência com memo de travel rule: `transfer` SEP‑0041 intacto + memo em tópico próprio
+pub fn emit_transfer_with_memo(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: &TravelRuleMemo,
+) {
+    emit_transfer(env, from, to, amount);
+    env.events()
+        .publish((symbol_short!("tr_memo"), from, to), memo.clone());
+}
+
+// Mint padrão SEP‑0041 (minter, destinatário)
+pub fn emit_mint(env: &Env, minter: &Address, to: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("mint"), minter, to), amount);
 }
 
 // Burn padrão SEP‑0041
@@ -20,6 +35,12 @@ pub fn emit_burn(env: &Env, from: &Address, amount: i128) {
     env.events().publish((symbol_short!("burn"), from), amount);
 }
 
+// Taxa cobrada numa transferência (o evento `transfer` leva o líquido)
+pub fn emit_transfer_fee(env: &Env, from: &Address, to: &Address, fee: i128) {
+    env.events()
+        .publish((symbol_short!("xfer_fee"), from, to), fee);
+}
+
 // Evento de aprovação (compatível SEP‑41 + ERC‑20)
 pub fn emit_approval(env: &Env, owner: &Address, spender: &Address, amount: i128) {
     env.events()
@@ -173,6 +194,7 @@ pub mod test_utils {
     // Re-exportar funções internas para uso em testes de integração
     pub use super::{
         emit_approval, emit_blacklist, emit_burn, emit_mint, emit_pause, emit_transfer,
-        emit_unpause, emit_vesting_created, emit_vesting_released, emit_vesting_revoked,
+        emit_transfer_fee, emit_transfer_with_memo, emit_unpause, emit_vesting_created,
+        emit_vesting_released, emit_vesting_revoked,
     };
 }
@@ -0,0 +1,228 @@
+use crate::events;
+use crate::storage;
+use crate::types::{BrazaError, FeeDestination, TransferFeeConfig};
+use crate::validation;
+use soroban_sdk::{symbol_short, Address, Env};
+
+// ============================================================================
+// TAXA DE TRANSFERÊNCIA
+// ============================================================================
+//
+// Cobrada sobre o valor bruto em `transfer` / `transfer_from` (e na liquidação
+// de transferências retidas). O destinatário recebe o líquido; a taxa vai para
+// a tesouraria ou é queimada. Remetente ou destinatário isento não paga, e o
+// próprio contrato é sempre isento.
+//
+// Independente da taxa, uma fração `burn_bps` de cada transferência pode ser
+// queimada (deflação), com as mesmas isenções.
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+pub fn get_fee_config(env: &Env) -> TransferFeeConfig {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("fee_cfg"))
+        .unwrap_or(TransferFeeConfig {
+            fee_bps: 0,
+            min_fee: 0,
+            max_fee: 0,
+            destination: FeeDestination::Burn,
+        })
+}
+
+pub fn set_fee_config(
+    env: &Env,
+    admin: &Address,
+    config: &TransferFeeConfig,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if config.fee_bps as i128 + get_transfer_burn_bps(env) as i128 > BPS_DENOMINATOR
+        || config.min_fee < 0
+        || config.max_fee < 0
+        || (config.max_fee > 0 && config.max_fee < config.min_fee)
+    {
+        return Err(BrazaError::InvalidAmount);
+    }
+    if let FeeDestination::Treasury(treasury) = &config.destination {
+        validation::require_not_blacklisted(env, treasury)?;
+    }
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("fee_cfg"), config);
+
+    env.events()
+        .publish((symbol_short!("fee_cfg"),), config.fee_bps);
+    Ok(())
+}
+
+pub fn get_transfer_burn_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("burn_bps"))
+        .unwrap_or(0)
+}
+
+pub fn set_transfer_burn_bps(env: &Env, admin: &Address, burn_bps: u32) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if burn_bps as i128 + get_fee_config(env).fee_bps as i128 > BPS_DENOMINATOR {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("burn_bps"), &burn_bps);
+
+    env.events().publish((symbol_short!("burn_bps"),), burn_bps);
+    Ok(())
+}
+
+pub fn is_fee_exempt(env: &Env, addr: &Address) -> bool {
+    if *addr == env.current_contract_address() {
+        return true;
+    }
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("fee_exm"), addr.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_fee_exempt(
+    env: &Env,
+    admin: &Address,
+    addr: &Address,
+    exempt: bool,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    let key = (symbol_short!("fee_exm"), addr.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events()
+        .publish((symbol_short!("fee_exm"), addr), exempt);
+    Ok(())
+}
+
+/// Total de taxas cobradas desde o deploy (tesouraria + queima).
+pub fn get_total_fees(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("fee_tot"))
+        .unwrap_or(0)
+}
+
+/// Taxa devida sobre `amount` (nunca maior que o próprio valor).
+pub fn calculate_fee(env: &Env, from: &Address, to: &Address, amount: i128) -> i128 {
+    let config = get_fee_config(env);
+    if config.fee_bps == 0 || amount <= 0 || from == to {
+        return 0;
+    }
+    if is_fee_exempt(env, from) || is_fee_exempt(env, to) {
+        return 0;
+    }
+
+    let mut fee = amount.saturating_mul(config.fee_bps as i128) / BPS_DENOMINATOR;
+    fee = fee.max(config.min_fee);
+    if config.max_fee > 0 {
+        fee = fee.min(config.max_fee);
+    }
+    fee.min(amount)
+}
+
+fn calculate_burn(env: &Env, from: &Address, to: &Address, amount: i128) -> i128 {
+    let burn_bps = get_transfer_burn_bps(env);
+    if burn_bps == 0 || amount <= 0 || from == to {
+        return 0;
+    }
+    if is_fee_exempt(env, from) || is_fee_exempt(env, to) {
+        return 0;
+    }
+    amount.saturating_mul(burn_bps as i128) / BPS_DENOMINATOR
+}
+
+/// Decompõe o bruto em (líquido, taxa, queima); recusa se nada chegaria ao destino.
+pub fn split_transfer(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(i128, i128, i128), BrazaError> {
+    let fee = calculate_fee(env, from, to, amount);
+    let burn = calculate_burn(env, from, to, amount).min(amount - fee);
+    let net = amount - fee - burn;
+    if net <= 0 {
+        return Err(BrazaError::FeeExceedsAmount);
+    }
+    Ok((net, fee, burn))
+}
+
+/// Aplica taxa e queima já debitadas de `from` (ver `split_transfer`).
+pub fn apply_deductions(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    fee: i128,
+    burn: i128,
+) -> Result<(), BrazaError> {
+    route_fee(env, from, to, fee)?;
+    burn_on_transfer(env, from, burn)
+}
+
+/// Queima parte da transferência; só circulante (nunca o travado em vesting).
+fn burn_on_transfer(env: &Env, from: &Address, burn: i128) -> Result<(), BrazaError> {
+    if burn <= 0 {
+        return Ok(());
+    }
+    storage::validate_burn_not_locked(env, burn)?;
+
+    let supply = storage::get_total_supply(env);
+    let new_supply = supply.checked_sub(burn).ok_or(BrazaError::InvalidAmount)?;
+    storage::set_total_supply(env, new_supply);
+    storage::add_total_burned(env, burn);
+
+    events::emit_burn(env, from, burn);
+    Ok(())
+}
+
+/// Encaminha uma taxa já debitada de `from` ao destino configurado.
+fn route_fee(env: &Env, from: &Address, to: &Address, fee: i128) -> Result<(), BrazaError> {
+    if fee <= 0 {
+        return Ok(());
+    }
+
+    match get_fee_config(env).destination {
+        FeeDestination::Treasury(treasury) => {
+            storage::add_balance(env, &treasury, fee);
+            events::emit_transfer(env, from, &treasury, fee);
+        }
+        FeeDestination::Burn => {
+            let supply = storage::get_total_supply(env);
+            let new_supply = supply.checked_sub(fee).ok_or(BrazaError::InvalidAmount)?;
+            storage::set_total_supply(env, new_supply);
+            storage::add_total_burned(env, fee);
+            events::emit_burn(env, from, fee);
+        }
+    }
+
+    let total = get_total_fees(env)
+        .checked_add(fee)
+        .ok_or(BrazaError::OverflowError)?;
+    env.storage()
+        .instance()
+        .set(&symbol_short!("fee_tot"), &total);
+
+    events::emit_transfer_fee(env, from, to, fee);
+    Ok(())
+}
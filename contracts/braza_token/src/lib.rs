@@ -7,7 +7,13 @@
 mod admin; // Lógica administrativa (Mint/Burn/Timelock)
 pub mod compliance; // Regras de KYC, GeoBlock e Risco
 mod compliance_cache; // Cache de compliance com lazy validation
+pub mod emission; // Emissão programada com halvings (estilo Bitcoin)
 pub mod events; // Emissão de eventos padronizados
+pub mod fees; // Taxa de transferência (tesouraria ou queima)
+pub mod minting; // Limites de cunhagem por janela
+pub mod pending; // Teto por transação e fila de transferências retidas
+pub mod reserve; // Prova de reserva (lastro atestado)
+pub mod restrictions; // Pré-checagem de transferências (can_transfer)
 pub mod storage; // Persistência de dados
 pub mod token; // Contrato Principal (BrazaToken)
 pub mod types; // Structs e Erros (BrazaError, VestingSchedule)
@@ -0,0 +1,316 @@
+use crate::storage;
+use crate::types::{BrazaError, MintLimits, MintLimitsProposal, MinterInfo};
+use crate::validation;
+use soroban_sdk::{symbol_short, Address, Env};
+
+// ============================================================================
+// LIMITES DE CUNHAGEM (RATE LIMIT POR JANELA)
+// ============================================================================
+//
+// Janelas fixas de `window_ledgers` ledgers. O contador global pertence à janela
+// do último mint (`storage::get_last_mint_time`); se a janela mudou, zera.
+
+pub fn get_mint_limits(env: &Env) -> MintLimits {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("mint_lim"))
+        .unwrap_or(MintLimits {
+            window_ledgers: 0,
+            max_per_window: 0,
+            max_per_minter: 0,
+        })
+}
+
+/// Aperta os tetos na hora. Afrouxar (subir, zerar ou encurtar a janela)
+/// exige `propose_mint_limits` + `MAX_SUPPLY_TIMELOCK_LEDGERS`, para que uma
+/// chave de admin comprometida não libere o mint na mesma transação.
+pub fn set_mint_limits(env: &Env, admin: &Address, limits: &MintLimits) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+    require_valid_limits(limits)?;
+
+    if loosens(&get_mint_limits(env), limits) {
+        return Err(BrazaError::MintLimitRaiseTimelocked);
+    }
+
+    apply_mint_limits(env, admin, limits);
+    Ok(())
+}
+
+pub fn propose_mint_limits(
+    env: &Env,
+    admin: &Address,
+    limits: &MintLimits,
+) -> Result<MintLimitsProposal, BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+    require_valid_limits(limits)?;
+
+    let proposal = MintLimitsProposal {
+        limits: limits.clone(),
+        eta_ledger: env
+            .ledger()
+            .sequence()
+            .saturating_add(storage::MAX_SUPPLY_TIMELOCK_LEDGERS),
+    };
+    env.storage()
+        .instance()
+        .set(&symbol_short!("mlim_prop"), &proposal);
+
+    env.events().publish(
+        (symbol_short!("mlim_prop"), admin),
+        (
+            limits.window_ledgers,
+            limits.max_per_window,
+            limits.max_per_minter,
+            proposal.eta_ledger,
+        ),
+    );
+    Ok(proposal)
+}
+
+pub fn execute_mint_limits(env: &Env, admin: &Address) -> Result<MintLimits, BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    let proposal = get_mint_limits_proposal(env).ok_or(BrazaError::NoPendingSupplyChange)?;
+    if env.ledger().sequence() < proposal.eta_ledger {
+        return Err(BrazaError::TimelockNotExpired);
+    }
+
+    apply_mint_limits(env, admin, &proposal.limits);
+    Ok(proposal.limits)
+}
+
+pub fn cancel_mint_limits(env: &Env, admin: &Address) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    get_mint_limits_proposal(env).ok_or(BrazaError::NoPendingSupplyChange)?;
+    env.storage().instance().remove(&symbol_short!("mlim_prop"));
+
+    env.events()
+        .publish((symbol_short!("mlim_cncl"), admin), ());
+    Ok(())
+}
+
+pub fn get_mint_limits_proposal(env: &Env) -> Option<MintLimitsProposal> {
+    env.storage().instance().get(&symbol_short!("mlim_prop"))
+}
+
+fn require_valid_limits(limits: &MintLimits) -> Result<(), BrazaError> {
+    let caps_set = limits.max_per_window > 0 || limits.max_per_minter > 0;
+    if limits.max_per_window < 0
+        || limits.max_per_minter < 0
+        || (caps_set && limits.window_ledgers == 0)
+    {
+        return Err(BrazaError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Algum teto ativo sobe, é desligado (0) ou passa a renovar mais rápido?
+fn loosens(cur: &MintLimits, new: &MintLimits) -> bool {
+    let cap_loosens = |c: i128, n: i128| c > 0 && (n == 0 || n > c);
+    let caps_active = cur.max_per_window > 0 || cur.max_per_minter > 0;
+    cap_loosens(cur.max_per_window, new.max_per_window)
+        || cap_loosens(cur.max_per_minter, new.max_per_minter)
+        || (caps_active && new.window_ledgers < cur.window_ledgers)
+}
+
+/// Grava os tetos e descarta proposta pendente (relativa aos tetos antigos).
+fn apply_mint_limits(env: &Env, admin: &Address, limits: &MintLimits) {
+    env.storage()
+        .instance()
+        .set(&symbol_short!("mint_lim"), limits);
+    env.storage().instance().remove(&symbol_short!("mlim_prop"));
+
+    env.events().publish(
+        (symbol_short!("mintlim"), admin),
+        (
+            limits.window_ledgers,
+            limits.max_per_window,
+            limits.max_per_minter,
+        ),
+    );
+}
+
+fn current_window(env: &Env, limits: &MintLimits) -> u32 {
+    env.ledger().sequence() / limits.window_ledgers.max(1)
+}
+
+/// Total cunhado na janela atual (todas as contas).
+pub fn get_window_minted(env: &Env) -> i128 {
+    let limits = get_mint_limits(env);
+    let window = current_window(env, &limits);
+    match storage::get_last_mint_time(env) {
+        Some(last) if last / limits.window_ledgers.max(1) == window => env
+            .storage()
+            .instance()
+            .get(&symbol_short!("mint_win"))
+            .unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Total cunhado por `minter` na janela atual.
+pub fn get_minter_window_minted(env: &Env, minter: &Address) -> i128 {
+    let limits = get_mint_limits(env);
+    let window = current_window(env, &limits);
+    let stored: Option<(u32, i128)> = env
+        .storage()
+        .persistent()
+        .get(&(symbol_short!("mint_wmn"), minter));
+    match stored {
+        Some((w, amount)) if w == window => amount,
+        _ => 0,
+    }
+}
+
+/// Quanto ainda pode ser cunhado na janela atual (i128::MAX se sem teto).
+pub fn remaining_window_allowance(env: &Env) -> i128 {
+    let limits = get_mint_limits(env);
+    if limits.max_per_window == 0 {
+        return i128::MAX;
+    }
+    limits
+        .max_per_window
+        .saturating_sub(get_window_minted(env))
+        .max(0)
+}
+
+pub fn remaining_minter_allowance(env: &Env, minter: &Address) -> i128 {
+    let limits = get_mint_limits(env);
+    if limits.max_per_minter == 0 {
+        return i128::MAX;
+    }
+    limits
+        .max_per_minter
+        .saturating_sub(get_minter_window_minted(env, minter))
+        .max(0)
+}
+
+/// Valida os tetos da janela e registra o mint (contadores + último mint).
+pub fn check_and_record_mint(env: &Env, minter: &Address, amount: i128) -> Result<(), BrazaError> {
+    if amount > remaining_window_allowance(env) || amount > remaining_minter_allowance(env, minter)
+    {
+        return Err(BrazaError::MintLimitExceeded);
+    }
+
+    let limits = get_mint_limits(env);
+    let window = current_window(env, &limits);
+
+    let window_total = get_window_minted(env).saturating_add(amount);
+    env.storage()
+        .instance()
+        .set(&symbol_short!("mint_win"), &window_total);
+
+    let minter_total = get_minter_window_minted(env, minter).saturating_add(amount);
+    env.storage().persistent().set(
+        &(symbol_short!("mint_wmn"), minter),
+        &(window, minter_total),
+    );
+
+    storage::set_last_mint_time(env, env.ledger().sequence());
+    Ok(())
+}
+
+// ============================================================================
+// REGISTRO DE MINTERS (PARCEIROS COM COTA)
+// ============================================================================
+
+fn minter_key(minter: &Address) -> (soroban_sdk::Symbol, Address) {
+    (symbol_short!("minter"), minter.clone())
+}
+
+/// (cota concedida, cota usada)
+fn get_minter_state(env: &Env, minter: &Address) -> Option<(i128, i128)> {
+    env.storage().persistent().get(&minter_key(minter))
+}
+
+fn set_minter_state(env: &Env, minter: &Address, quota: i128, used: i128) {
+    env.storage()
+        .persistent()
+        .set(&minter_key(minter), &(quota, used));
+}
+
+pub fn get_minter_info(env: &Env, minter: &Address) -> Option<MinterInfo> {
+    get_minter_state(env, minter).map(|(quota, used)| MinterInfo {
+        quota,
+        used,
+        remaining: quota.saturating_sub(used).max(0),
+    })
+}
+
+pub fn is_minter(env: &Env, minter: &Address) -> bool {
+    env.storage().persistent().has(&minter_key(minter))
+}
+
+/// Registra um minter (ou redefine sua cota, zerando o uso).
+pub fn add_minter(
+    env: &Env,
+    admin: &Address,
+    minter: &Address,
+    quota: i128,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+    validation::require_not_blacklisted(env, minter)?;
+
+    if quota < 0 {
+        return Err(BrazaError::InvalidAmount);
+    }
+    set_minter_state(env, minter, quota, 0);
+
+    env.events()
+        .publish((symbol_short!("mntr_add"), minter), quota);
+    Ok(())
+}
+
+pub fn remove_minter(env: &Env, admin: &Address, minter: &Address) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    env.storage().persistent().remove(&minter_key(minter));
+
+    env.events().publish((symbol_short!("mntr_rm"), minter), ());
+    Ok(())
+}
+
+/// Admin reabastece a cota de um minter existente.
+pub fn refill_minter_quota(
+    env: &Env,
+    admin: &Address,
+    minter: &Address,
+    amount: i128,
+) -> Result<MinterInfo, BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+    validation::require_positive_amount(amount)?;
+
+    let (quota, used) = get_minter_state(env, minter).ok_or(BrazaError::Unauthorized)?;
+    let new_quota = quota.checked_add(amount).ok_or(BrazaError::OverflowError)?;
+    set_minter_state(env, minter, new_quota, used);
+
+    env.events()
+        .publish((symbol_short!("mntr_fill"), minter), amount);
+    get_minter_info(env, minter).ok_or(BrazaError::Unauthorized)
+}
+
+/// Debita `amount` da cota do minter.
+pub fn consume_minter_quota(env: &Env, minter: &Address, amount: i128) -> Result<(), BrazaError> {
+    let (quota, used) = get_minter_state(env, minter).ok_or(BrazaError::Unauthorized)?;
+    let new_used = used.checked_add(amount).ok_or(BrazaError::OverflowError)?;
+    if new_used > quota {
+        return Err(BrazaError::MinterQuotaExceeded);
+    }
+    set_minter_state(env, minter, quota, new_used);
+    Ok(())
+}
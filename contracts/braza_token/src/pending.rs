@@ -0,0 +1,344 @@
+use crate::compliance;
+use crate::events;
+use crate::fees;
+use crate::storage;
+use crate::types::{BrazaError, PendingTransfer, TransferLimits, TravelRuleMemo};
+use crate::validation;
+use soroban_sdk::{symbol_short, Address, Env, Vec};
+
+// ============================================================================
+// TETO POR TRANSAÇÃO E FILA DE RETENÇÃO (LARGE TRANSFERS)
+// ============================================================================
+
+pub fn set_transfer_limits(
+    env: &Env,
+    admin: &Address,
+    limits: &TransferLimits,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if limits.max_amount < 0 || limits.hold_threshold < 0 {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("tx_lims"), limits);
+
+    env.events().publish(
+        (symbol_short!("txlim_set"),),
+        (limits.max_amount, limits.hold_threshold),
+    );
+
+    Ok(())
+}
+
+pub fn get_transfer_limits(env: &Env) -> TransferLimits {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("tx_lims"))
+        .unwrap_or(TransferLimits {
+            max_amount: 0,
+            hold_threshold: 0,
+            auto_release_ledgers: 0,
+        })
+}
+
+/// Teto rígido por transferência
+pub fn require_within_max(env: &Env, amount: i128) -> Result<(), BrazaError> {
+    let limits = get_transfer_limits(env);
+    if limits.max_amount > 0 && amount > limits.max_amount {
+        return Err(BrazaError::TransferAmountExceeded);
+    }
+    Ok(())
+}
+
+/// Transferências acima do limiar não liquidam na hora
+pub fn requires_hold(env: &Env, amount: i128) -> bool {
+    let limits = get_transfer_limits(env);
+    limits.hold_threshold > 0 && amount > limits.hold_threshold
+}
+
+/// Admin ou compliance officer
+pub fn require_compliance_officer(env: &Env, caller: &Address) -> Result<(), BrazaError> {
+    if validation::require_admin(env, caller).is_ok() {
+        return Ok(());
+    }
+    match storage::get_compliance_officer(env) {
+        Some(officer) if &officer == caller => Ok(()),
+        _ => Err(BrazaError::Unauthorized),
+    }
+}
+
+// ============================================================================
+// CRIAÇÃO E LIQUIDAÇÃO
+// ============================================================================
+
+/// Debita o remetente e registra a transferência retida.
+/// `count_volume` soma o valor ao volume diário do remetente (como em `transfer`).
+/// `spender` identifica a allowance já consumida por um `transfer_from`.
+pub fn create_pending_transfer(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: Option<TravelRuleMemo>,
+    count_volume: bool,
+    spender: Option<&Address>,
+) -> Result<u32, BrazaError> {
+    storage::sub_balance(env, from, amount)?;
+    add_held(env, amount)?;
+    if count_volume {
+        compliance::check_and_update_daily_volume(env, from, amount)?;
+    }
+
+    let id = get_pending_count(env);
+    env.storage()
+        .instance()
+        .set(&symbol_short!("pend_cnt"), &(id + 1));
+
+    let now = env.ledger().sequence();
+    let auto = get_transfer_limits(env).auto_release_ledgers;
+    let release_ledger = if auto > 0 {
+        now.saturating_add(auto)
+    } else {
+        0
+    };
+
+    let pending = PendingTransfer {
+        id,
+        from: from.clone(),
+        to: to.clone(),
+        amount,
+        created_ledger: now,
+        release_ledger,
+        has_memo: memo.is_some(),
+        counted_volume: count_volume,
+        spender: spender.cloned(),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("pend"), id), &pending);
+    if let Some(m) = memo {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("pend_memo"), id), &m);
+    }
+
+    let mut ids = get_pending_ids(env, from);
+    ids.push_back(id);
+    set_pending_ids(env, from, &ids);
+
+    env.events()
+        .publish((symbol_short!("hold_new"), from, to), (id, amount));
+
+    Ok(id)
+}
+
+pub fn approve_pending_transfer(env: &Env, officer: &Address, id: u32) -> Result<(), BrazaError> {
+    officer.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_not_paused(env)?;
+    require_compliance_officer(env, officer)?;
+
+    let pending = take_pending(env, id)?;
+    settle(env, &pending)?;
+
+    env.events()
+        .publish((symbol_short!("hold_ok"), officer), id);
+
+    Ok(())
+}
+
+pub fn reject_pending_transfer(env: &Env, officer: &Address, id: u32) -> Result<(), BrazaError> {
+    officer.require_auth();
+    storage::bump_critical_storage(env);
+    require_compliance_officer(env, officer)?;
+
+    // Rejeitar só devolve ao remetente, então é permitido mesmo pausado
+    let pending = take_pending(env, id)?;
+    take_pending_memo(env, id);
+    sub_held(env, pending.amount)?;
+    storage::add_balance(env, &pending.from, pending.amount);
+    if pending.counted_volume {
+        compliance::refund_daily_volume(env, &pending.from, pending.amount, pending.created_ledger);
+    }
+    if let Some(spender) = &pending.spender {
+        let allowance = storage::get_allowance(env, &pending.from, spender)
+            .checked_add(pending.amount)
+            .ok_or(BrazaError::OverflowError)?;
+        storage::set_allowance(env, &pending.from, spender, allowance);
+    }
+
+    env.events()
+        .publish((symbol_short!("hold_rej"), officer), id);
+
+    Ok(())
+}
+
+/// Liberação automática (permissionless) após `release_ledger`.
+pub fn release_pending_transfer(env: &Env, id: u32) -> Result<(), BrazaError> {
+    storage::bump_critical_storage(env);
+    validation::require_not_paused(env)?;
+
+    let pending = get_pending_transfer(env, id).ok_or(BrazaError::PendingTransferNotFound)?;
+    if pending.release_ledger == 0 || env.ledger().sequence() < pending.release_ledger {
+        return Err(BrazaError::TimelockNotExpired);
+    }
+
+    let pending = take_pending(env, id)?;
+    settle(env, &pending)?;
+
+    env.events().publish((symbol_short!("hold_auto"),), id);
+
+    Ok(())
+}
+
+fn settle(env: &Env, pending: &PendingTransfer) -> Result<(), BrazaError> {
+    // O status das partes pode ter mudado durante a retenção
+    require_settleable(env, &pending.from)?;
+    require_settleable(env, &pending.to)?;
+    compliance::require_corridor_allowed(env, &pending.from, &pending.to)?;
+
+    sub_held(env, pending.amount)?;
+
+    // Taxa e queima cobradas na liquidação (rejeição devolve o bruto)
+    let (net, fee, burn) = fees::split_transfer(env, &pending.from, &pending.to, pending.amount)?;
+
+    let to_balance = storage::get_balance(env, &pending.to);
+    let new_to_balance = to_balance
+        .checked_add(net)
+        .ok_or(BrazaError::InvalidAmount)?;
+    storage::set_balance(env, &pending.to, new_to_balance);
+    fees::apply_deductions(env, &pending.from, &pending.to, fee, burn)?;
+
+    compliance::check_and_update_daily_inflow(env, &pending.to, &pending.from, net)?;
+
+    match take_pending_memo(env, pending.id) {
+        Some(memo) => events::emit_transfer_with_memo(env, &pending.from, &pending.to, net, &memo),
+        None => events::emit_transfer(env, &pending.from, &pending.to, net),
+    }
+    Ok(())
+}
+
+// ============================================================================
+// CONSULTAS
+// ============================================================================
+
+/// Ids já emitidos; os ainda retidos são consultáveis com `get_pending_transfer(0..count)`.
+pub fn get_pending_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("pend_cnt"))
+        .unwrap_or(0)
+}
+
+pub fn get_pending_transfer(env: &Env, id: u32) -> Option<PendingTransfer> {
+    env.storage().persistent().get(&(symbol_short!("pend"), id))
+}
+
+pub fn get_pending_memo(env: &Env, id: u32) -> Option<TravelRuleMemo> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("pend_memo"), id))
+}
+
+/// Transferências retidas em que `from` é o remetente
+pub fn get_pending_transfers(env: &Env, from: &Address) -> Vec<PendingTransfer> {
+    let mut v = Vec::new(env);
+    for id in get_pending_ids(env, from).iter() {
+        if let Some(p) = get_pending_transfer(env, id) {
+            v.push_back(p);
+        }
+    }
+    v
+}
+
+pub fn get_total_held(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("held_amt"))
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// FUNÇÕES AUXILIARES
+// ============================================================================
+
+/// Mesmas exigências de `transfer`, conferidas de novo na liquidação
+fn require_settleable(env: &Env, user: &Address) -> Result<(), BrazaError> {
+    validation::require_not_blacklisted(env, user)?;
+    validation::require_kyc_level(env, user, 2)?;
+    validation::require_country_allowed(env, user)?;
+    validation::require_acceptable_risk(env, user, 50)
+}
+
+fn take_pending(env: &Env, id: u32) -> Result<PendingTransfer, BrazaError> {
+    let pending = get_pending_transfer(env, id).ok_or(BrazaError::PendingTransferNotFound)?;
+
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("pend"), id));
+
+    let ids = get_pending_ids(env, &pending.from);
+    let mut remaining = Vec::new(env);
+    for other in ids.iter() {
+        if other != id {
+            remaining.push_back(other);
+        }
+    }
+    set_pending_ids(env, &pending.from, &remaining);
+
+    Ok(pending)
+}
+
+fn take_pending_memo(env: &Env, id: u32) -> Option<TravelRuleMemo> {
+    let memo = get_pending_memo(env, id);
+    if memo.is_some() {
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("pend_memo"), id));
+    }
+    memo
+}
+
+fn get_pending_ids(env: &Env, from: &Address) -> Vec<u32> {
+    let key = (symbol_short!("pend_usr"), from);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_pending_ids(env: &Env, from: &Address, ids: &Vec<u32>) {
+    let key = (symbol_short!("pend_usr"), from);
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, ids);
+    }
+}
+
+fn add_held(env: &Env, amount: i128) -> Result<(), BrazaError> {
+    let new = get_total_held(env)
+        .checked_add(amount)
+        .ok_or(BrazaError::OverflowError)?;
+    env.storage()
+        .instance()
+        .set(&symbol_short!("held_amt"), &new);
+    Ok(())
+}
+
+fn sub_held(env: &Env, amount: i128) -> Result<(), BrazaError> {
+    let cur = get_total_held(env);
+    if cur < amount {
+        return Err(BrazaError::InsufficientBalance);
+    }
+    env.storage()
+        .instance()
+        .set(&symbol_short!("held_amt"), &(cur - amount));
+    Ok(())
+}
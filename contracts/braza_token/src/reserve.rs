@@ -0,0 +1,130 @@
+use crate::storage;
+use crate::types::{BrazaError, ReserveAttestation};
+use crate::validation;
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
+
+// ============================================================================
+// PROVA DE RESERVA (MINT LASTREADO)
+// ============================================================================
+//
+// O admin nomeia um atestador de reserva. A partir daí, todo mint exige que
+// `total_supply + amount` caiba na última atestação ainda válida (não mais
+// antiga que `max_age_ledgers`). Sem atestador configurado, não há trava.
+
+/// Validade padrão de uma atestação: ~1 dia de ledgers.
+pub const DEFAULT_RESERVE_MAX_AGE: u32 = 17_280;
+
+pub fn get_reserve_attester(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&symbol_short!("res_attr"))
+}
+
+pub fn get_reserve_max_age(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("res_age"))
+        .unwrap_or(DEFAULT_RESERVE_MAX_AGE)
+}
+
+pub fn get_attestation(env: &Env) -> Option<ReserveAttestation> {
+    env.storage().instance().get(&symbol_short!("res_last"))
+}
+
+pub fn set_reserve_attester(
+    env: &Env,
+    admin: &Address,
+    attester: &Address,
+    max_age_ledgers: u32,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    if max_age_ledgers == 0 {
+        return Err(BrazaError::InvalidAmount);
+    }
+    write_reserve_attester(env, attester, max_age_ledgers);
+
+    env.events()
+        .publish((symbol_short!("res_attr"), attester), max_age_ledgers);
+    Ok(())
+}
+
+/// Grava o papel sem checar auth (usado também no `initialize`).
+pub fn write_reserve_attester(env: &Env, attester: &Address, max_age_ledgers: u32) {
+    env.storage()
+        .instance()
+        .set(&symbol_short!("res_attr"), attester);
+    env.storage()
+        .instance()
+        .set(&symbol_short!("res_age"), &max_age_ledgers);
+}
+
+/// Atestador publica o valor de reserva e o hash do relatório de auditoria.
+pub fn attest_reserve(
+    env: &Env,
+    attester: &Address,
+    amount: i128,
+    report_hash: BytesN<32>,
+) -> Result<ReserveAttestation, BrazaError> {
+    attester.require_auth();
+    storage::bump_critical_storage(env);
+
+    match get_reserve_attester(env) {
+        Some(a) if a == *attester => {}
+        _ => return Err(BrazaError::Unauthorized),
+    }
+    if amount < 0 {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    let attestation = ReserveAttestation {
+        amount,
+        attester: attester.clone(),
+        ledger: env.ledger().sequence(),
+        report_hash,
+    };
+    env.storage()
+        .instance()
+        .set(&symbol_short!("res_last"), &attestation);
+
+    env.events()
+        .publish((symbol_short!("reserve"), attester), amount);
+    Ok(attestation)
+}
+
+pub fn is_attestation_stale(env: &Env, attestation: &ReserveAttestation) -> bool {
+    env.ledger().sequence().saturating_sub(attestation.ledger) > get_reserve_max_age(env)
+}
+
+/// Reserva disponível para novos mints (None = sem trava de reserva).
+pub fn available_reserve(env: &Env) -> Option<i128> {
+    get_reserve_attester(env)?;
+    let reserve = match get_attestation(env) {
+        Some(a) if !is_attestation_stale(env, &a) => a.amount,
+        _ => 0,
+    };
+    Some(
+        reserve
+            .saturating_sub(storage::get_total_supply(env))
+            .max(0),
+    )
+}
+
+/// `total_supply + amount` deve caber na última atestação válida.
+pub fn require_within_reserve(env: &Env, amount: i128) -> Result<(), BrazaError> {
+    if get_reserve_attester(env).is_none() {
+        return Ok(());
+    }
+    let attestation = match get_attestation(env) {
+        Some(a) if !is_attestation_stale(env, &a) => a,
+        _ => return Err(BrazaError::ReserveAttestationStale),
+    };
+
+    let new_supply = storage::get_total_supply(env)
+        .checked_add(amount)
+        .ok_or(BrazaError::OverflowError)?;
+    if new_supply > attestation.amount {
+        return Err(BrazaError::ReserveExceeded);
+    }
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use crate::compliance;
+use crate::compliance_cache;
+use crate::fees;
+use crate::pending;
+use crate::storage;
+use crate::types::{TransferCheck, TransferRestriction};
+use crate::validation;
+use soroban_sdk::{Address, Env};
+
+// ============================================================================
+// PRÉ-CHECAGEM (DETECT TRANSFER RESTRICTION)
+// ============================================================================
+//
+// Reproduz, sem alterar estado, a mesma sequência de validações de
+// `execute_transfer` / `execute_transfer_from` em token.rs.
+// Sempre que uma regra for adicionada lá, deve ser espelhada aqui.
+
+fn ok() -> TransferCheck {
+    TransferCheck {
+        code: TransferRestriction::Ok,
+        party: None,
+    }
+}
+
+fn restricted(code: TransferRestriction, party: Option<&Address>) -> TransferCheck {
+    TransferCheck {
+        code,
+        party: party.cloned(),
+    }
+}
+
+/// Espelha `compliance_cache::validate_pair_with_cache(from, to, 2, 50)` com códigos granulares.
+fn check_party_cached(
+    env: &Env,
+    user: &Address,
+    from: &Address,
+    to: &Address,
+) -> TransferRestriction {
+    if compliance::get_kyc_level(env, user) < 2 {
+        return TransferRestriction::KycInsufficient;
+    }
+    if compliance::get_risk_score(env, user) > 50 {
+        return TransferRestriction::RiskTooHigh;
+    }
+    if storage::is_blacklisted(env, user) {
+        return TransferRestriction::Blacklisted;
+    }
+    if compliance_cache::validate_with_cache(env, user, 2, 50, false).is_err()
+        && (!compliance::is_pair_corridor_allowed(env, from, to)
+            || compliance_cache::validate_with_cache(env, user, 2, 50, true).is_err())
+    {
+        return TransferRestriction::CountryNotAllowed;
+    }
+    TransferRestriction::Ok
+}
+
+fn check_corridor(env: &Env, from: &Address, to: &Address) -> TransferCheck {
+    let (from_code, to_code) = match (
+        compliance::get_country_code(env, from),
+        compliance::get_country_code(env, to),
+    ) {
+        (Some(f), Some(t)) => (f, t),
+        (None, _) => return restricted(TransferRestriction::CountryNotAllowed, Some(from)),
+        (_, None) => return restricted(TransferRestriction::CountryNotAllowed, Some(to)),
+    };
+
+    let rule = compliance::get_corridor_rule(env, from_code, to_code);
+    if rule.blocked {
+        return restricted(TransferRestriction::CorridorBlocked, Some(to));
+    }
+    if rule.min_kyc > 0 && compliance::get_kyc_level(env, from) < rule.min_kyc {
+        return restricted(TransferRestriction::CorridorKycRequired, Some(from));
+    }
+    ok()
+}
+
+/// Teto, retenção e limites de recebimento (etapa final comum)
+fn check_settlement(env: &Env, from: &Address, to: &Address, amount: i128) -> TransferCheck {
+    if pending::require_within_max(env, amount).is_err() {
+        return restricted(TransferRestriction::TransferAmountExceeded, None);
+    }
+
+    let net = match fees::split_transfer(env, from, to, amount) {
+        Ok((net, _, _)) => net,
+        Err(_) => return restricted(TransferRestriction::FeeExceedsAmount, None),
+    };
+
+    // Transferências retidas só checam recebimento na liquidação
+    if pending::requires_hold(env, amount) {
+        return ok();
+    }
+
+    // Recebimento conta o líquido (após taxa e queima)
+    if compliance::would_exceed_daily_inflow(env, to, net) {
+        return restricted(TransferRestriction::InflowLimitExceeded, Some(to));
+    }
+    if compliance::would_exceed_velocity(env, to, from) {
+        return restricted(TransferRestriction::VelocityLimitExceeded, Some(to));
+    }
+    ok()
+}
+
+/// Pré-checagem de `transfer` (sem memo de travel rule).
+pub fn detect_transfer_restriction(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> TransferCheck {
+    if validation::require_not_paused(env).is_err() {
+        return restricted(TransferRestriction::Paused, None);
+    }
+    if validation::require_positive_amount(amount).is_err() {
+        return restricted(TransferRestriction::InvalidAmount, None);
+    }
+    if validation::require_sufficient_balance(env, from, amount).is_err() {
+        return restricted(TransferRestriction::InsufficientBalance, Some(from));
+    }
+
+    if from == to {
+        return ok();
+    }
+
+    if compliance::require_travel_rule_memo(env, amount, false).is_err() {
+        return restricted(TransferRestriction::TravelRuleMemoRequired, None);
+    }
+
+    for party in [from, to] {
+        let code = check_party_cached(env, party, from, to);
+        if code != TransferRestriction::Ok {
+            return restricted(code, Some(party));
+        }
+    }
+
+    let corridor = check_corridor(env, from, to);
+    if corridor.code != TransferRestriction::Ok {
+        return corridor;
+    }
+
+    if validation::require_daily_volume_limit(env, from, amount).is_err() {
+        return restricted(TransferRestriction::DailyLimitExceeded, Some(from));
+    }
+
+    check_settlement(env, from, to, amount)
+}
+
+/// Pré-checagem de `transfer_from` (sem memo de travel rule).
+pub fn detect_transfer_from_restriction(
+    env: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> TransferCheck {
+    if validation::require_not_paused(env).is_err() {
+        return restricted(TransferRestriction::Paused, None);
+    }
+
+    for party in [spender, from, to] {
+        if storage::is_blacklisted(env, party) {
+            return restricted(TransferRestriction::Blacklisted, Some(party));
+        }
+    }
+
+    if validation::require_positive_amount(amount).is_err() {
+        return restricted(TransferRestriction::InvalidAmount, None);
+    }
+    if validation::require_sufficient_balance(env, from, amount).is_err() {
+        return restricted(TransferRestriction::InsufficientBalance, Some(from));
+    }
+    if compliance::require_travel_rule_memo(env, amount, false).is_err() {
+        return restricted(TransferRestriction::TravelRuleMemoRequired, None);
+    }
+
+    for party in [spender, from, to] {
+        if compliance::require_country_allowed(env, party).is_err() {
+            return restricted(TransferRestriction::CountryNotAllowed, Some(party));
+        }
+    }
+    for party in [spender, from, to] {
+        if compliance::require_kyc_level(env, party, 2).is_err() {
+            return restricted(TransferRestriction::KycInsufficient, Some(party));
+        }
+    }
+    for party in [spender, from, to] {
+        if compliance::require_acceptable_risk(env, party, 50).is_err() {
+            return restricted(TransferRestriction::RiskTooHigh, Some(party));
+        }
+    }
+
+    let corridor = check_corridor(env, from, to);
+    if corridor.code != TransferRestriction::Ok {
+        return corridor;
+    }
+
+    if storage::get_allowance(env, from, spender) < amount {
+        return restricted(TransferRestriction::InsufficientAllowance, Some(spender));
+    }
+
+    check_settlement(env, from, to, amount)
+}
@@ -1,12 +1,20 @@
-use crate::types::{BrazaError, TokenMetadata, VestingSchedule};
+use crate::types::{
+    BrazaError, SupplyCapProposal, SupplyStats, TokenMetadata, VestingSchedule, VestingStats,
+    VestingSummary,
+};
 use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
 // ---------------------------
 // CONSTANTES
 // ---------------------------
+// Valores de referência para deploy; o teto efetivo vive no estado (get_max_supply)
 pub const MAX_SUPPLY: i128 = 2_100_000_000_000_000; // 210 milhões BRZ
 pub const INITIAL_SUPPLY: i128 = 1_000_000_000_000_000; // 100 milhões BRZ
+pub const MAX_SUPPLY_TIMELOCK_LEDGERS: u32 = 17_280; // ~1 dia para aumentar o teto
 pub const MAX_VESTING_SCHEDULES: u32 = 50;
+// Layout do storage (VestingSchedule com funder/curve/clock/tempo/voting_weight/
+// revoked_vested). Contratos sem a chave são do layout anterior: não há migração.
+pub const STORAGE_VERSION: u32 = 2;
 pub const MAX_GLOBAL_VESTING_SCHEDULES: u32 = 10_000;
 pub const VESTING_STORAGE_FEE: i128 = 1_000_000;
 pub const MIN_VESTING_AMOUNT: i128 = 10_000_000;
@@ -15,6 +23,7 @@ pub const CRITICAL_STORAGE_TTL: u32 = 6_307_200;
 pub const CRITICAL_STORAGE_THRESHOLD: u32 = 518_400;
 pub const LEDGER_THRESHOLD_SHARED: u32 = 518_400;
 pub const LEDGER_BUMP_SHARED: u32 = 6_307_200;
+pub const SECONDS_PER_LEDGER: u64 = 5; // Tempo médio de fechamento de ledger
 
 // ---------------------------
 // TTL FUNCTIONS
@@ -41,6 +50,19 @@ const STORAGE_FEE_POOL: Symbol = symbol_short!("strpfee");
 const LAST_VEST_TIME: Symbol = symbol_short!("lstvest");
 const ALLOWANCE: Symbol = symbol_short!("allow");
 const LOCKED_BALANCE: Symbol = symbol_short!("locked");
+const COMPLIANCE_OFFICER: Symbol = symbol_short!("comp_off");
+const GOVERNANCE: Symbol = symbol_short!("gov");
+const VEST_FREE: Symbol = symbol_short!("vst_free");
+const VEST_ARCH: Symbol = symbol_short!("vst_arch");
+const VEST_ARCH_CNT: Symbol = symbol_short!("vst_acnt");
+const MAX_SUP: Symbol = symbol_short!("max_sup");
+const INIT_SUP: Symbol = symbol_short!("init_sup");
+const MAX_SUP_PROP: Symbol = symbol_short!("max_prop");
+const TOTAL_BURNED: Symbol = symbol_short!("tot_brn");
+const TOTAL_MINTED: Symbol = symbol_short!("tot_mint");
+const FORCE_BURNED: Symbol = symbol_short!("frc_brn");
+const HOLDERS: Symbol = symbol_short!("holders");
+const STOR_VER: Symbol = symbol_short!("stor_ver");
 
 // ---------------------------
 // BALANCE TTL
@@ -96,17 +118,105 @@ pub fn remove_vesting_schedule(env: &Env, beneficiary: &Address, id: u32) {
 
 // GET ALL VESTINGS
 pub fn get_all_vesting_schedules(env: &Env, beneficiary: &Address) -> Vec<VestingSchedule> {
+    let mut v = Vec::new(env);
+    for (_, s) in get_all_vesting_schedules_with_ids(env, beneficiary).iter() {
+        v.push_back(s);
+    }
+    v
+}
+
+// GET ALL VESTINGS (COM IDS) — ids começam em 1 (ver increment_vesting_count)
+pub fn get_all_vesting_schedules_with_ids(
+    env: &Env,
+    beneficiary: &Address,
+) -> Vec<(u32, VestingSchedule)> {
     let count = get_vesting_count(env, beneficiary);
     let mut v = Vec::new(env);
 
-    for id in 0..count {
+    for id in 1..=count {
         if let Some(s) = get_vesting_schedule(env, beneficiary, id) {
-            v.push_back(s);
+            v.push_back((id, s));
         }
     }
     v
 }
 
+// SLOTS LIBERADOS (ids reaproveitáveis por novos schedules)
+pub fn get_free_vesting_ids(env: &Env, beneficiary: &Address) -> Vec<u32> {
+    let key = (VEST_FREE, beneficiary);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_free_vesting_ids(env: &Env, beneficiary: &Address, ids: &Vec<u32>) {
+    let key = (VEST_FREE, beneficiary);
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, ids);
+    }
+}
+
+// ACTIVE COUNT (ids emitidos menos slots liberados)
+pub fn get_active_vesting_count(env: &Env, beneficiary: &Address) -> u32 {
+    get_vesting_count(env, beneficiary).saturating_sub(get_free_vesting_ids(env, beneficiary).len())
+}
+
+// RELEASE SLOT — remove o schedule, libera o id e o slot global
+pub fn release_vesting_slot(env: &Env, beneficiary: &Address, id: u32) {
+    remove_vesting_schedule(env, beneficiary, id);
+
+    let mut free = get_free_vesting_ids(env, beneficiary);
+    free.push_back(id);
+    set_free_vesting_ids(env, beneficiary, &free);
+
+    decrement_global_vesting_count(env);
+}
+
+// ARCHIVED SUMMARIES — um registro por índice (VEST_ARCH, beneficiário, n)
+pub fn get_vesting_summary_count(env: &Env, beneficiary: &Address) -> u32 {
+    let key = (VEST_ARCH_CNT, beneficiary);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn get_vesting_summary(env: &Env, beneficiary: &Address, index: u32) -> Option<VestingSummary> {
+    let key = (VEST_ARCH, beneficiary, index);
+    env.storage().persistent().get(&key)
+}
+
+/// Página de resumos a partir de `start` (no máximo `MAX_VESTING_SCHEDULES`).
+pub fn get_vesting_summaries(
+    env: &Env,
+    beneficiary: &Address,
+    start: u32,
+    limit: u32,
+) -> Vec<VestingSummary> {
+    let count = get_vesting_summary_count(env, beneficiary);
+    let end = start
+        .saturating_add(limit.min(MAX_VESTING_SCHEDULES))
+        .min(count);
+    let mut list = Vec::new(env);
+    for index in start..end {
+        if let Some(summary) = get_vesting_summary(env, beneficiary, index) {
+            list.push_back(summary);
+        }
+    }
+    list
+}
+
+pub fn push_vesting_summary(env: &Env, beneficiary: &Address, summary: &VestingSummary) {
+    let index = get_vesting_summary_count(env, beneficiary);
+    let entry = (VEST_ARCH, beneficiary, index);
+    let count = (VEST_ARCH_CNT, beneficiary);
+    let storage = env.storage().persistent();
+    storage.set(&entry, summary);
+    storage.set(&count, &(index + 1));
+    storage.extend_ttl(&entry, CRITICAL_STORAGE_THRESHOLD, CRITICAL_STORAGE_TTL);
+    storage.extend_ttl(&count, CRITICAL_STORAGE_THRESHOLD, CRITICAL_STORAGE_TTL);
+}
+
 // GLOBAL COUNT
 pub fn get_global_vesting_count(env: &Env) -> u32 {
     env.storage()
@@ -120,8 +230,7 @@ fn increment_global_vesting_count(env: &Env) {
     env.storage().instance().set(&GLOBAL_VEST_COUNT, &(c + 1));
 }
 
-#[allow(dead_code)]
-fn decrement_global_vesting_count(env: &Env) {
+pub fn decrement_global_vesting_count(env: &Env) {
     let c = get_global_vesting_count(env);
     if c > 0 {
         env.storage().instance().set(&GLOBAL_VEST_COUNT, &(c - 1));
@@ -231,13 +340,28 @@ pub fn validate_burn_not_locked(env: &Env, burn: i128) -> Result<(), BrazaError>
 }
 
 // GLOBAL STATS
-pub fn get_vesting_stats(env: &Env) -> (u32, u32, i128, u32) {
-    (
-        get_global_vesting_count(env),
-        MAX_GLOBAL_VESTING_SCHEDULES,
-        get_storage_fee_pool(env),
-        MAX_VESTING_SCHEDULES,
-    )
+pub fn get_vesting_stats(env: &Env) -> VestingStats {
+    VestingStats {
+        active_schedules: get_global_vesting_count(env),
+        max_global_schedules: MAX_GLOBAL_VESTING_SCHEDULES,
+        fee_pool: get_storage_fee_pool(env),
+        max_per_beneficiary: MAX_VESTING_SCHEDULES,
+        locked_balance: get_locked_balance(env),
+    }
+}
+
+pub fn get_supply_stats(env: &Env) -> SupplyStats {
+    SupplyStats {
+        total_supply: get_total_supply(env),
+        max_supply: get_max_supply(env),
+        locked_in_vesting: get_locked_balance(env),
+        circulating_supply: get_circulating_supply(env),
+        total_minted: get_total_minted(env),
+        total_burned: get_total_burned(env),
+        force_burned: get_force_burned(env),
+        fee_pool: get_storage_fee_pool(env),
+        holders: get_holder_count(env),
+    }
 }
 
 pub fn is_near_global_vesting_limit(env: &Env) -> bool {
@@ -278,20 +402,20 @@ pub fn set_last_burn_time(env: &Env, ledger: u32) {
     env.storage().instance().set(&LAST_BURN_TIME, &ledger);
 }
 
-// LAST VEST CREATION
-pub fn get_last_vesting_creation_time(env: &Env, user: &Address) -> Option<u32> {
-    let key = (LAST_VEST_TIME, user);
+// LAST VEST CREATION — por (funder, beneficiário): um financiador não trava o outro
+pub fn get_last_vesting_creation_time(env: &Env, funder: &Address, user: &Address) -> Option<u32> {
+    let key = (LAST_VEST_TIME, funder, user);
     env.storage().persistent().get(&key)
 }
 
-pub fn set_last_vesting_creation_time(env: &Env, user: &Address, seq: u32) {
-    let key = (LAST_VEST_TIME, user);
+pub fn set_last_vesting_creation_time(env: &Env, funder: &Address, user: &Address, seq: u32) {
+    let key = (LAST_VEST_TIME, funder, user);
     env.storage().persistent().set(&key, &seq);
 }
 
 // VESTING COOLDOWN
-pub fn is_vesting_cooldown_expired(env: &Env, user: &Address) -> bool {
-    match get_last_vesting_creation_time(env, user) {
+pub fn is_vesting_cooldown_expired(env: &Env, funder: &Address, user: &Address) -> bool {
+    match get_last_vesting_creation_time(env, funder, user) {
         None => true,
         Some(last) => {
             let now = env.ledger().sequence();
@@ -300,8 +424,8 @@ pub fn is_vesting_cooldown_expired(env: &Env, user: &Address) -> bool {
     }
 }
 
-pub fn get_vesting_cooldown_remaining(env: &Env, user: &Address) -> Option<u32> {
-    let last = get_last_vesting_creation_time(env, user)?;
+pub fn get_vesting_cooldown_remaining(env: &Env, funder: &Address, user: &Address) -> Option<u32> {
+    let last = get_last_vesting_creation_time(env, funder, user)?;
     let now = env.ledger().sequence();
     let el = now.saturating_sub(last);
     if el < VESTING_CREATION_COOLDOWN_LEDGERS {
@@ -369,6 +493,7 @@ pub fn reset_global_state(env: &Env) {
 // CLEANUP PERSISTENT KEYS
 #[allow(dead_code)]
 pub fn cleanup_balance(env: &Env, addr: &Address) {
+    set_balance(env, addr, 0);
     let key = (BALANCE, addr);
     env.storage().persistent().remove(&key);
 }
@@ -376,12 +501,13 @@ pub fn cleanup_balance(env: &Env, addr: &Address) {
 #[allow(dead_code)]
 pub fn cleanup_vesting_user(env: &Env, addr: &Address) {
     let cnt = get_vesting_count(env, addr);
-    for id in 0..cnt {
+    for id in 1..=cnt {
         let key = (VESTING, addr, id);
         env.storage().persistent().remove(&key);
     }
     let cnt_key = (VEST_CNT, addr);
     env.storage().persistent().remove(&cnt_key);
+    env.storage().persistent().remove(&(VEST_FREE, addr));
 }
 
 // SAFE GET BOOL
@@ -411,6 +537,24 @@ pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&ADMIN, admin);
 }
 
+// COMPLIANCE OFFICER
+pub fn get_compliance_officer(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&COMPLIANCE_OFFICER)
+}
+
+pub fn set_compliance_officer(env: &Env, officer: &Address) {
+    env.storage().instance().set(&COMPLIANCE_OFFICER, officer);
+}
+
+// GOVERNANCE (multisig que executa aumentos de teto)
+pub fn get_governance(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&GOVERNANCE)
+}
+
+pub fn set_governance(env: &Env, governance: &Address) {
+    env.storage().instance().set(&GOVERNANCE, governance);
+}
+
 // PAUSE
 pub fn is_paused(env: &Env) -> bool {
     env.storage().instance().get(&PAUSED).unwrap_or(false)
@@ -429,6 +573,78 @@ pub fn set_total_supply(env: &Env, amt: i128) {
     env.storage().instance().set(&SUPPLY, &amt);
 }
 
+/// Acumulado de tudo que saiu do supply por queima (burn, taxa, burn-on-transfer...)
+pub fn get_total_burned(env: &Env) -> i128 {
+    env.storage().instance().get(&TOTAL_BURNED).unwrap_or(0)
+}
+
+pub fn add_total_burned(env: &Env, amt: i128) {
+    let total = get_total_burned(env).saturating_add(amt);
+    env.storage().instance().set(&TOTAL_BURNED, &total);
+}
+
+/// Acumulado cunhado (alocações iniciais, mint e emissão)
+pub fn get_total_minted(env: &Env) -> i128 {
+    env.storage().instance().get(&TOTAL_MINTED).unwrap_or(0)
+}
+
+pub fn add_total_minted(env: &Env, amt: i128) {
+    let total = get_total_minted(env).saturating_add(amt);
+    env.storage().instance().set(&TOTAL_MINTED, &total);
+}
+
+/// Parcela de `get_total_burned` vinda de queimas forçadas (compliance/judicial)
+pub fn get_force_burned(env: &Env) -> i128 {
+    env.storage().instance().get(&FORCE_BURNED).unwrap_or(0)
+}
+
+pub fn add_force_burned(env: &Env, amt: i128) {
+    let total = get_force_burned(env).saturating_add(amt);
+    env.storage().instance().set(&FORCE_BURNED, &total);
+}
+
+/// Endereços com saldo > 0 (mantido por `set_balance`).
+pub fn get_holder_count(env: &Env) -> u32 {
+    env.storage().instance().get(&HOLDERS).unwrap_or(0)
+}
+
+/// 0 = contrato implantado antes do versionamento (layout anterior)
+pub fn get_storage_version(env: &Env) -> u32 {
+    env.storage().instance().get(&STOR_VER).unwrap_or(0)
+}
+
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage().instance().set(&STOR_VER, &version);
+}
+
+pub fn get_max_supply(env: &Env) -> i128 {
+    env.storage().instance().get(&MAX_SUP).unwrap_or(MAX_SUPPLY)
+}
+
+pub fn set_max_supply(env: &Env, amt: i128) {
+    env.storage().instance().set(&MAX_SUP, &amt);
+}
+
+pub fn get_initial_supply(env: &Env) -> i128 {
+    env.storage().instance().get(&INIT_SUP).unwrap_or(0)
+}
+
+pub fn set_initial_supply(env: &Env, amt: i128) {
+    env.storage().instance().set(&INIT_SUP, &amt);
+}
+
+pub fn get_max_supply_proposal(env: &Env) -> Option<SupplyCapProposal> {
+    env.storage().instance().get(&MAX_SUP_PROP)
+}
+
+pub fn set_max_supply_proposal(env: &Env, proposal: &SupplyCapProposal) {
+    env.storage().instance().set(&MAX_SUP_PROP, proposal);
+}
+
+pub fn remove_max_supply_proposal(env: &Env) {
+    env.storage().instance().remove(&MAX_SUP_PROP);
+}
+
 // BALANCE
 pub fn get_balance(env: &Env, user: &Address) -> i128 {
     let key = (BALANCE, user);
@@ -437,7 +653,18 @@ pub fn get_balance(env: &Env, user: &Address) -> i128 {
 
 pub fn set_balance(env: &Env, user: &Address, amt: i128) {
     let key = (BALANCE, user);
+    let old: i128 = env.storage().persistent().get(&key).unwrap_or(0);
     env.storage().persistent().set(&key, &amt);
+
+    // Contagem de holders acompanha transições de/para saldo zero
+    let holders = get_holder_count(env);
+    if old <= 0 && amt > 0 {
+        env.storage().instance().set(&HOLDERS, &(holders + 1));
+    } else if old > 0 && amt <= 0 {
+        env.storage()
+            .instance()
+            .set(&HOLDERS, &holders.saturating_sub(1));
+    }
 }
 
 // REMOVE ALLOWANCE
@@ -446,18 +673,25 @@ pub fn remove_allowance(env: &Env, from: &Address, spender: &Address) {
     env.storage().persistent().remove(&key);
 }
 
-// increment_vesting_count
+// increment_vesting_count — reaproveita ids liberados antes de emitir um novo
 pub fn increment_vesting_count(env: &Env, user: &Address) -> Result<u32, BrazaError> {
-    let cur = get_vesting_count(env, user);
-    if cur >= MAX_VESTING_SCHEDULES {
-        return Err(BrazaError::MaxVestingSchedulesExceeded);
-    }
-
     let global = get_global_vesting_count(env);
     if global >= MAX_GLOBAL_VESTING_SCHEDULES {
         return Err(BrazaError::GlobalVestingLimitExceeded);
     }
 
+    let mut free = get_free_vesting_ids(env, user);
+    if let Some(id) = free.pop_front() {
+        set_free_vesting_ids(env, user, &free);
+        increment_global_vesting_count(env);
+        return Ok(id);
+    }
+
+    let cur = get_vesting_count(env, user);
+    if cur >= MAX_VESTING_SCHEDULES {
+        return Err(BrazaError::MaxVestingSchedulesExceeded);
+    }
+
     let new = cur + 1;
     env.storage().persistent().set(&(VEST_CNT, user), &new);
     increment_global_vesting_count(env);
@@ -530,6 +764,7 @@ pub fn create_test_vesting(
 
     let schedule = VestingSchedule {
         beneficiary: beneficiary.clone(),
+        funder: get_admin(env),
         total_amount: amount,
         released_amount: 0,
         start_ledger: start,
@@ -537,19 +772,28 @@ pub fn create_test_vesting(
         duration_ledgers: duration,
         revocable: false,
         revoked: false,
+        revoked_vested: 0,
+        curve: crate::types::VestingCurve::Linear,
+        clock: crate::types::VestingClock::Ledger,
+        start_time: env.ledger().timestamp(),
+        cliff_seconds: cliff as u64 * SECONDS_PER_LEDGER,
+        duration_seconds: duration as u64 * SECONDS_PER_LEDGER,
+        voting_weight: false,
     };
 
-    set_vesting_schedule(env, beneficiary, id - 1, &schedule);
-    Ok(id - 1)
+    set_vesting_schedule(env, beneficiary, id, &schedule);
+    Ok(id)
 }
 
 #[cfg(any(test, feature = "testutils"))]
 #[cfg(not(tarpaulin_include))]
 pub fn clear_all_vestings(env: &Env, beneficiary: &Address) {
-    let count = get_vesting_count(env, beneficiary);
-    for id in 0..count {
+    // ids começam em 1 (ver increment_vesting_count)
+    for (id, _) in get_all_vesting_schedules_with_ids(env, beneficiary).iter() {
         remove_vesting_schedule(env, beneficiary, id);
+        decrement_global_vesting_count(env);
     }
+    set_free_vesting_ids(env, beneficiary, &Vec::new(env));
 
     let key = (VEST_CNT, beneficiary);
     env.storage().persistent().set(&key, &0u32);
@@ -621,6 +865,32 @@ mod locked_extra_tests {
     }
 }
 
+#[cfg(test)]
+#[cfg(not(tarpaulin_include))]
+mod vesting_helper_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_create_and_clear_use_one_based_ids() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::BrazaToken);
+        let user = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            set_admin(&env, &user);
+            let first = create_test_vesting(&env, &user, 1000, 0, 0, 100).unwrap();
+            let second = create_test_vesting(&env, &user, 1000, 0, 0, 100).unwrap();
+            assert_eq!((first, second), (1, 2));
+            assert_eq!(get_all_vesting_schedules(&env, &user).len(), 2);
+
+            clear_all_vestings(&env, &user);
+            assert!(get_vesting_schedule(&env, &user, 1).is_none());
+            assert!(get_vesting_schedule(&env, &user, 2).is_none());
+            assert_eq!(get_global_vesting_count(&env), 0);
+        });
+    }
+}
+
 #[cfg(test)]
 #[cfg(not(tarpaulin_include))]
 mod reentrancy_tests {
@@ -1,9 +1,21 @@
 #![allow(unused_imports)]
 use crate::compliance;
 use crate::compliance_cache;
+use crate::emission;
 use crate::events;
+use crate::fees;
+use crate::minting;
+use crate::pending;
+use crate::reserve;
+use crate::restrictions;
 use crate::storage;
-use crate::types::{BrazaError, TokenMetadata, VestingSchedule};
+use crate::types::{
+    BrazaError, CorridorRule, EmissionConfig, InitConfig, MintLimits, MintLimitsProposal,
+    MinterInfo, PendingTransfer, ReserveAttestation, SupplyCapProposal, SupplyStats, TokenMetadata,
+    TransferCheck, TransferFeeConfig, TransferLimits, TravelRuleMemo, VelocityRule, VestingClock,
+    VestingCurve, VestingGrant, VestingSchedule, VestingStartLimits, VestingStats, VestingSummary,
+    VestingTerms, VestingTimeline, VestingUsage,
+};
 use crate::validation;
 use crate::vesting;
 use soroban_sdk::{
@@ -38,32 +50,336 @@ impl<'a> Drop for ReentrancyGuard<'a> {
     }
 }
 
+// ============================================================================
+// TRANSFERÊNCIAS — IMPLEMENTAÇÃO COMPARTILHADA
+// ============================================================================
+// Toda regra nova aqui deve ser espelhada em restrictions.rs (can_transfer).
+
+fn execute_transfer(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: Option<TravelRuleMemo>,
+) -> Result<(), BrazaError> {
+    from.require_auth();
+
+    storage::bump_critical_storage(env);
+
+    // ✅ VALIDAÇÃO SEP-41: Se from == to
+    if from == to {
+        validation::require_not_paused(env)?;
+        validation::require_positive_amount(amount)?;
+        validation::require_sufficient_balance(env, from, amount)?;
+        events::emit_transfer(env, from, to, amount);
+        return Ok(());
+    }
+
+    // ✅ VALIDAÇÕES BÁSICAS
+    validation::require_not_paused(env)?;
+    validation::require_positive_amount(amount)?;
+    validation::require_sufficient_balance(env, from, amount)?;
+    compliance::require_travel_rule_memo(env, amount, memo.is_some())?;
+
+    // ✅ VALIDAÇÕES COM CACHE CORRETO
+    compliance_cache::validate_pair_with_cache(env, from, to, 2, 50)?;
+    compliance::require_corridor_allowed(env, from, to)?;
+
+    // ✅ Validação de daily limit
+    validation::require_daily_volume_limit(env, from, amount)?;
+
+    // ✅ Teto por transação e retenção de valores altos
+    pending::require_within_max(env, amount)?;
+
+    // ✅ Taxa e queima saem do bruto; destinatário recebe o líquido
+    let (net, fee, burn) = fees::split_transfer(env, from, to, amount)?;
+
+    if pending::requires_hold(env, amount) {
+        pending::create_pending_transfer(env, from, to, amount, memo, true, None)?;
+        return Ok(());
+    }
+
+    // ✅ BUMP #2: Antes de MODIFICAR balances
+    storage::bump_critical_storage(env);
+
+    let from_balance = storage::get_balance(env, from);
+    let to_balance = storage::get_balance(env, to);
+
+    let new_from_balance = from_balance
+        .checked_sub(amount)
+        .ok_or(BrazaError::InsufficientBalance)?;
+    let new_to_balance = to_balance
+        .checked_add(net)
+        .ok_or(BrazaError::InvalidAmount)?;
+
+    storage::set_balance(env, from, new_from_balance);
+    storage::set_balance(env, to, new_to_balance);
+    fees::apply_deductions(env, from, to, fee, burn)?;
+
+    // ✅ BUMP #3: Antes de MODIFICAR daily volume
+    storage::bump_critical_storage(env);
+
+    compliance::check_and_update_daily_volume(env, from, amount)?;
+    compliance::check_and_update_daily_inflow(env, to, from, net)?;
+
+    // ✅ Invalidar cache após modificação
+    compliance_cache::invalidate_cache(env, from);
+
+    match &memo {
+        Some(m) => events::emit_transfer_with_memo(env, from, to, net, m),
+        None => events::emit_transfer(env, from, to, net),
+    }
+
+    Ok(())
+}
+
+fn execute_transfer_from(
+    env: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: Option<TravelRuleMemo>,
+) -> Result<(), BrazaError> {
+    spender.require_auth();
+    storage::bump_critical_storage(env);
+
+    validation::require_not_paused(env)?;
+    validation::require_not_blacklisted(env, spender)?;
+    validation::require_not_blacklisted(env, from)?;
+    validation::require_not_blacklisted(env, to)?;
+
+    validation::require_positive_amount(amount)?;
+    validation::require_sufficient_balance(env, from, amount)?;
+    compliance::require_travel_rule_memo(env, amount, memo.is_some())?;
+
+    validation::require_country_allowed(env, spender)?;
+    validation::require_country_allowed(env, from)?;
+    validation::require_country_allowed(env, to)?;
+
+    validation::require_kyc_level(env, spender, 2)?;
+    validation::require_kyc_level(env, from, 2)?;
+    validation::require_kyc_level(env, to, 2)?;
+
+    validation::require_acceptable_risk(env, spender, 50)?;
+    validation::require_acceptable_risk(env, from, 50)?;
+    validation::require_acceptable_risk(env, to, 50)?;
+
+    compliance::require_corridor_allowed(env, from, to)?;
+
+    let current_allowance = storage::get_allowance(env, from, spender);
+
+    if current_allowance < amount {
+        env.events().publish(
+            (symbol_short!("all_fail"), spender, from),
+            (amount, current_allowance),
+        );
+        return Err(BrazaError::InsufficientAllowance);
+    }
+
+    let new_allowance = current_allowance
+        .checked_sub(amount)
+        .ok_or(BrazaError::InsufficientAllowance)?;
+
+    storage::set_allowance(env, from, spender, new_allowance);
+    storage::bump_allowance(env, from, spender);
+
+    pending::require_within_max(env, amount)?;
+    let (net, fee, burn) = fees::split_transfer(env, from, to, amount)?;
+
+    if pending::requires_hold(env, amount) {
+        pending::create_pending_transfer(env, from, to, amount, memo, false, Some(spender))?;
+        env.events().publish(
+            (symbol_short!("all_used"), spender, from),
+            (amount, new_allowance),
+        );
+        return Ok(());
+    }
+
+    let from_balance = storage::get_balance(env, from);
+    let to_balance = storage::get_balance(env, to);
+
+    let new_from_balance = from_balance
+        .checked_sub(amount)
+        .ok_or(BrazaError::InsufficientBalance)?;
+    let new_to_balance = to_balance
+        .checked_add(net)
+        .ok_or(BrazaError::InvalidAmount)?;
+
+    storage::set_balance(env, from, new_from_balance);
+    storage::set_balance(env, to, new_to_balance);
+    fees::apply_deductions(env, from, to, fee, burn)?;
+
+    compliance::check_and_update_daily_inflow(env, to, from, net)?;
+
+    match &memo {
+        Some(m) => events::emit_transfer_with_memo(env, from, to, net, m),
+        None => events::emit_transfer(env, from, to, net),
+    }
+
+    env.events().publish(
+        (symbol_short!("all_used"), spender, from),
+        (amount, new_allowance),
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// VESTING — IMPLEMENTAÇÃO COMPARTILHADA
+// ============================================================================
+
+/// Termos lineares em ledgers (formato original de `create_vesting`).
+fn ledger_terms(cliff_ledgers: u32, duration_ledgers: u32, curve: VestingCurve) -> VestingTerms {
+    VestingTerms {
+        clock: VestingClock::Ledger,
+        start: None,
+        cliff: cliff_ledgers as u64,
+        duration: duration_ledgers as u64,
+        curve,
+    }
+}
+
+fn execute_create_vesting(
+    env: &Env,
+    funder: &Address,
+    beneficiary: &Address,
+    total_amount: i128,
+    revocable: bool,
+    terms: VestingTerms,
+) -> Result<u32, BrazaError> {
+    funder.require_auth();
+    storage::bump_critical_storage(env);
+
+    validation::require_not_paused(env)?;
+    validation::require_not_blacklisted(env, funder)?;
+    vesting::require_vesting_funder(env, funder)?;
+    validation::require_vesting_cooldown_expired(env, funder, beneficiary)?;
+
+    fund_vesting(env, funder, beneficiary, total_amount, revocable, terms)
+}
+
+/// Debita o `funder` (valor + taxa de storage), trava o valor e cria o schedule.
+/// Auth, pausa e cooldown já foram checados pelo chamador.
+fn fund_vesting(
+    env: &Env,
+    funder: &Address,
+    beneficiary: &Address,
+    total_amount: i128,
+    revocable: bool,
+    terms: VestingTerms,
+) -> Result<u32, BrazaError> {
+    validation::require_valid_vesting_params(total_amount, terms.cliff, terms.duration)?;
+    validation::require_valid_vesting_curve(
+        total_amount,
+        terms.duration,
+        terms.clock,
+        &terms.curve,
+    )?;
+
+    // Taxa anti-spam vai para o fee pool (fica no contrato, fora do locked)
+    let required = total_amount
+        .checked_add(storage::VESTING_STORAGE_FEE)
+        .ok_or(BrazaError::OverflowError)?;
+    validation::require_sufficient_balance(env, funder, required)?;
+
+    let funder_balance = storage::get_balance(env, funder);
+    let new_funder_balance = funder_balance
+        .checked_sub(required)
+        .ok_or(BrazaError::InsufficientBalance)?;
+
+    storage::set_balance(env, funder, new_funder_balance);
+    storage::increment_locked_balance(env, total_amount)?;
+    storage::add_to_storage_fee_pool(env, storage::VESTING_STORAGE_FEE);
+
+    let schedule_id =
+        vesting::create_vesting_schedule(env, funder, beneficiary, total_amount, revocable, terms)?;
+    storage::set_last_vesting_creation_time(env, funder, beneficiary, env.ledger().sequence());
+
+    events::emit_vesting_created(env, beneficiary, schedule_id, total_amount);
+    env.events()
+        .publish((symbol_short!("v_fund"), funder, beneficiary), schedule_id);
+    Ok(schedule_id)
+}
+
+// ============================================================================
+// MINT — IMPLEMENTAÇÃO COMPARTILHADA
+// ============================================================================
+
+/// Cunha para `to` em nome de `minter` (admin ou parceiro; auth feita pelo chamador).
+fn execute_mint(env: &Env, minter: &Address, to: &Address, amount: i128) -> Result<(), BrazaError> {
+    storage::bump_critical_storage(env);
+    validation::require_not_paused(env)?;
+    validation::require_positive_amount(amount)?;
+    validation::require_max_supply_not_exceeded(env, amount)?;
+    reserve::require_within_reserve(env, amount)?;
+
+    validation::require_not_blacklisted(env, to)?;
+    validation::require_country_allowed(env, to)?;
+    validation::require_kyc_level(env, to, 2)?;
+    validation::require_acceptable_risk(env, to, 50)?;
+    minting::check_and_record_mint(env, minter, amount)?;
+
+    let bal = storage::get_balance(env, to);
+    let new_bal = bal.checked_add(amount).ok_or(BrazaError::OverflowError)?;
+
+    let supply = storage::get_total_supply(env);
+    let new_supply = supply
+        .checked_add(amount)
+        .ok_or(BrazaError::MaxSupplyExceeded)?;
+
+    storage::set_balance(env, to, new_bal);
+    storage::set_total_supply(env, new_supply);
+    storage::add_total_minted(env, amount);
+
+    events::emit_mint(env, minter, to, amount);
+    Ok(())
+}
+
+/// Libera o vestido de um schedule para o beneficiário (auth feita pelo chamador).
+fn execute_release(env: &Env, beneficiary: &Address, schedule_id: u32) -> Result<i128, BrazaError> {
+    storage::bump_critical_storage(env);
+    validation::require_not_paused(env)?;
+
+    let releasable = vesting::release_vested_tokens(env, beneficiary, schedule_id)?;
+
+    let bal = storage::get_balance(env, beneficiary);
+    let new_bal = bal
+        .checked_add(releasable)
+        .ok_or(BrazaError::InvalidAmount)?;
+
+    storage::set_balance(env, beneficiary, new_bal);
+    storage::decrement_locked_balance(env, releasable)?;
+
+    events::emit_vesting_released(env, beneficiary, schedule_id, releasable);
+    Ok(releasable)
+}
+
 #[contractimpl]
 impl BrazaToken {
     // ============================================================================
     // INICIALIZAÇÃO
     // ============================================================================
 
-    pub fn initialize(
-        env: Env,
-        admin: Address,
-        name: String,
-        symbol: String,
-    ) -> Result<(), BrazaError> {
+    pub fn initialize(env: Env, admin: Address, config: InitConfig) -> Result<(), BrazaError> {
         if env.storage().instance().has(&symbol_short!("admin")) {
             return Err(BrazaError::AlreadyInitialized);
         }
+        let initial_supply = validation::require_valid_init_config(&config)?;
 
         storage::set_admin(&env, &admin);
         storage::set_paused(&env, false);
+        storage::set_storage_version(&env, storage::STORAGE_VERSION);
 
-        storage::set_balance(&env, &admin, storage::INITIAL_SUPPLY);
-        storage::set_total_supply(&env, storage::INITIAL_SUPPLY);
+        storage::set_max_supply(&env, config.max_supply);
+        storage::set_initial_supply(&env, initial_supply);
+        storage::set_total_supply(&env, initial_supply);
+        storage::add_total_minted(&env, initial_supply);
 
         let metadata = TokenMetadata {
-            name,
-            symbol,
-            decimals: 7,
+            name: config.name,
+            symbol: config.symbol,
+            decimals: config.decimals,
         };
         storage::set_metadata(&env, &metadata);
 
@@ -71,17 +387,44 @@ impl BrazaToken {
         // ADICIONAR: Setar país, KYC e risk score DIRETAMENTE NO STORAGE
         // (Não usar compliance:: functions que exigem require_auth)
         // ============================================================================
-        let br_code = String::from_str(&env, "BR");
-        let key_country = (symbol_short!("ctry"), &admin);
-        env.storage().persistent().set(&key_country, &br_code);
+        let policy = config.compliance;
+        if !policy.admin_country.is_empty() {
+            let key_country = (symbol_short!("country"), &admin);
+            env.storage()
+                .persistent()
+                .set(&key_country, &policy.admin_country);
+        }
 
         let key_kyc = (symbol_short!("kyc"), &admin);
-        env.storage().persistent().set(&key_kyc, &3u32);
+        env.storage()
+            .persistent()
+            .set(&key_kyc, &policy.admin_kyc_level);
 
         let key_risk = (symbol_short!("risk"), &admin);
         env.storage().persistent().set(&key_risk, &0u32);
 
-        events::emit_mint(&env, &admin, storage::INITIAL_SUPPLY);
+        for code in policy.blocked_countries.iter() {
+            let key = (symbol_short!("blk_ctry"), code);
+            env.storage().persistent().set(&key, &true);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("tr_thr"), &policy.travel_rule_threshold);
+
+        // Papéis
+        if let Some(officer) = config.compliance_officer {
+            storage::set_compliance_officer(&env, &officer);
+        }
+        if let Some(attester) = config.reserve_attester {
+            reserve::write_reserve_attester(&env, &attester, reserve::DEFAULT_RESERVE_MAX_AGE);
+        }
+
+        // Distribuição inicial
+        for a in config.allocations.iter() {
+            let bal = storage::get_balance(&env, &a.recipient);
+            storage::set_balance(&env, &a.recipient, bal + a.amount);
+            events::emit_mint(&env, &admin, &a.recipient, a.amount);
+        }
 
         Ok(())
     }
@@ -90,6 +433,7 @@ impl BrazaToken {
     // UPGRADE
     // ============================================================================
 
+    /// Só entre versões com o mesmo `get_storage_version` (ver scripts/upgrade.sh).
     pub fn update_code(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), BrazaError> {
         let admin = storage::get_admin(&env);
         admin.require_auth();
@@ -98,6 +442,11 @@ impl BrazaToken {
         Ok(())
     }
 
+    /// Versão do layout de storage gravada no `initialize` (0 = layout anterior).
+    pub fn get_storage_version(env: Env) -> u32 {
+        storage::get_storage_version(&env)
+    }
+
     // ============================================================================
     // SEP‑41 — LEITURA
     // ============================================================================
@@ -136,60 +485,27 @@ impl BrazaToken {
         }
         storage::set_reentrancy_guard(&env, true);
 
-        let result = (|| {
-            from.require_auth();
-
-            storage::bump_critical_storage(&env);
-
-            // ✅ VALIDAÇÃO SEP-41: Se from == to
-            if from == to {
-                validation::require_not_paused(&env)?;
-                validation::require_positive_amount(amount)?;
-                validation::require_sufficient_balance(&env, &from, amount)?;
-                events::emit_transfer(&env, &from, &to, amount);
-                return Ok(());
-            }
-
-            // ✅ VALIDAÇÕES BÁSICAS
-            validation::require_not_paused(&env)?;
-            validation::require_positive_amount(amount)?;
-            validation::require_sufficient_balance(&env, &from, amount)?;
-
-            // ✅ VALIDAÇÕES COM CACHE CORRETO
-            compliance_cache::validate_with_cache(&env, &from, 2, 50)?;
-            compliance_cache::validate_with_cache(&env, &to, 2, 50)?;
-
-            // ✅ Validação de daily limit
-            validation::require_daily_volume_limit(&env, &from, amount)?;
-
-            // ✅ BUMP #2: Antes de MODIFICAR balances
-            storage::bump_critical_storage(&env);
-
-            let from_balance = storage::get_balance(&env, &from);
-            let to_balance = storage::get_balance(&env, &to);
-
-            let new_from_balance = from_balance
-                .checked_sub(amount)
-                .ok_or(BrazaError::InsufficientBalance)?;
-            let new_to_balance = to_balance
-                .checked_add(amount)
-                .ok_or(BrazaError::InvalidAmount)?;
-
-            storage::set_balance(&env, &from, new_from_balance);
-            storage::set_balance(&env, &to, new_to_balance);
+        let result = execute_transfer(&env, &from, &to, amount, None);
 
-            // ✅ BUMP #3: Antes de MODIFICAR daily volume
-            storage::bump_critical_storage(&env);
-
-            compliance::check_and_update_daily_volume(&env, &from, amount)?;
-
-            // ✅ Invalidar cache após modificação
-            compliance_cache::invalidate_cache(&env, &from);
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
 
-            events::emit_transfer(&env, &from, &to, amount);
+    /// Transferência com memo de travel rule (hash dos dados off-chain + VASPs).
+    /// Obrigatória acima de `get_travel_rule_threshold`.
+    pub fn transfer_with_memo(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        memo: TravelRuleMemo,
+    ) -> Result<(), BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
 
-            Ok(())
-        })();
+        let result = execute_transfer(&env, &from, &to, amount, Some(memo));
 
         storage::set_reentrancy_guard(&env, false);
         result
@@ -207,74 +523,46 @@ impl BrazaToken {
         }
         storage::set_reentrancy_guard(&env, true);
 
-        let result = (|| {
-            spender.require_auth();
-            storage::bump_critical_storage(&env);
-
-            validation::require_not_paused(&env)?;
-            validation::require_not_blacklisted(&env, &spender)?;
-            validation::require_not_blacklisted(&env, &from)?;
-            validation::require_not_blacklisted(&env, &to)?;
-
-            validation::require_positive_amount(amount)?;
-            validation::require_sufficient_balance(&env, &from, amount)?;
-
-            validation::require_country_allowed(&env, &spender)?;
-            validation::require_country_allowed(&env, &from)?;
-            validation::require_country_allowed(&env, &to)?;
-
-            validation::require_kyc_level(&env, &spender, 2)?;
-            validation::require_kyc_level(&env, &from, 2)?;
-            validation::require_kyc_level(&env, &to, 2)?;
-
-            validation::require_acceptable_risk(&env, &spender, 50)?;
-            validation::require_acceptable_risk(&env, &from, 50)?;
-            validation::require_acceptable_risk(&env, &to, 50)?;
-
-            let current_allowance = storage::get_allowance(&env, &from, &spender);
-
-            if current_allowance < amount {
-                env.events().publish(
-                    (symbol_short!("all_fail"), &spender, &from),
-                    (amount, current_allowance),
-                );
-                return Err(BrazaError::InsufficientAllowance);
-            }
-
-            let new_allowance = current_allowance
-                .checked_sub(amount)
-                .ok_or(BrazaError::InsufficientAllowance)?;
-
-            storage::set_allowance(&env, &from, &spender, new_allowance);
-            storage::bump_allowance(&env, &from, &spender);
-
-            let from_balance = storage::get_balance(&env, &from);
-            let to_balance = storage::get_balance(&env, &to);
-
-            let new_from_balance = from_balance
-                .checked_sub(amount)
-                .ok_or(BrazaError::InsufficientBalance)?;
-            let new_to_balance = to_balance
-                .checked_add(amount)
-                .ok_or(BrazaError::InvalidAmount)?;
-
-            storage::set_balance(&env, &from, new_from_balance);
-            storage::set_balance(&env, &to, new_to_balance);
+        let result = execute_transfer_from(&env, &spender, &from, &to, amount, None);
 
-            events::emit_transfer(&env, &from, &to, amount);
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
 
-            env.events().publish(
-                (symbol_short!("all_used"), &spender, &from),
-                (amount, new_allowance),
-            );
+    pub fn transfer_from_with_memo(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+        memo: TravelRuleMemo,
+    ) -> Result<(), BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
 
-            Ok(())
-        })();
+        let result = execute_transfer_from(&env, &spender, &from, &to, amount, Some(memo));
 
         storage::set_reentrancy_guard(&env, false);
         result
     }
 
+    /// Pré-checagem sem efeitos: retorna a primeira regra que faria `transfer` falhar.
+    pub fn can_transfer(env: Env, from: Address, to: Address, amount: i128) -> TransferCheck {
+        restrictions::detect_transfer_restriction(&env, &from, &to, amount)
+    }
+
+    pub fn can_transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> TransferCheck {
+        restrictions::detect_transfer_from_restriction(&env, &spender, &from, &to, amount)
+    }
+
     // ============================================================================
     // ALLOWANCE
     // ============================================================================
@@ -383,169 +671,805 @@ impl BrazaToken {
         }
         storage::set_reentrancy_guard(&env, true);
 
-        let result = (|| {
-            let admin = storage::get_admin(&env);
-            admin.require_auth();
-
-            storage::bump_critical_storage(&env);
-            validation::require_not_paused(&env)?;
-            validation::require_positive_amount(amount)?;
-            validation::require_max_supply_not_exceeded(&env, amount)?;
-
-            validation::require_not_blacklisted(&env, &to)?;
-            validation::require_country_allowed(&env, &to)?;
-            validation::require_kyc_level(&env, &to, 2)?;
-            validation::require_acceptable_risk(&env, &to, 50)?;
-
-            let bal = storage::get_balance(&env, &to);
-            let new_bal = bal.checked_add(amount).ok_or(BrazaError::OverflowError)?;
-
-            let supply = storage::get_total_supply(&env);
-            let new_supply = supply
-                .checked_add(amount)
-                .ok_or(BrazaError::MaxSupplyExceeded)?;
-
-            storage::set_balance(&env, &to, new_bal);
-            storage::set_total_supply(&env, new_supply);
-
-            events::emit_mint(&env, &to, amount);
-            Ok(())
-        })();
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+        let result = execute_mint(&env, &admin, &to, amount);
 
         storage::set_reentrancy_guard(&env, false);
         result
     }
 
-    // ============================================================================
-    // BURN (User)
-    // ============================================================================
-
-    pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), BrazaError> {
+    /// Mint por minter parceiro registrado, consumindo a própria cota.
+    pub fn minter_mint(
+        env: Env,
+        minter: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), BrazaError> {
         if storage::is_reentrancy_locked(&env) {
             return Err(BrazaError::Unauthorized);
         }
         storage::set_reentrancy_guard(&env, true);
 
         let result = (|| {
-            from.require_auth();
-
-            storage::bump_critical_storage(&env);
-            validation::require_not_paused(&env)?;
+            minter.require_auth();
+            validation::require_not_blacklisted(&env, &minter)?;
             validation::require_positive_amount(amount)?;
-            validation::require_sufficient_balance(&env, &from, amount)?;
-
-            validation::require_not_blacklisted(&env, &from)?;
-            storage::validate_burn_not_locked(&env, amount)?;
-
-            let bal = storage::get_balance(&env, &from);
-            let new_bal = bal
-                .checked_sub(amount)
-                .ok_or(BrazaError::InsufficientBalance)?;
-
-            let supply = storage::get_total_supply(&env);
-            let new_supply = supply
-                .checked_sub(amount)
-                .ok_or(BrazaError::InvalidAmount)?;
-
-            storage::set_balance(&env, &from, new_bal);
-            storage::set_total_supply(&env, new_supply);
-
-            events::emit_burn(&env, &from, amount);
-            Ok(())
+            minting::consume_minter_quota(&env, &minter, amount)?;
+            execute_mint(&env, &minter, &to, amount)
         })();
 
         storage::set_reentrancy_guard(&env, false);
         result
     }
 
-    // ============================================================================
-    // VESTING
-    // ============================================================================
-
-    pub fn create_vesting(
+    pub fn add_minter(
         env: Env,
-        beneficiary: Address,
-        total_amount: i128,
-        cliff_ledgers: u32,
-        duration_ledgers: u32,
-        revocable: bool,
-    ) -> Result<u32, BrazaError> {
-        if storage::is_reentrancy_locked(&env) {
-            return Err(BrazaError::Unauthorized);
-        }
-        storage::set_reentrancy_guard(&env, true);
+        admin: Address,
+        minter: Address,
+        quota: i128,
+    ) -> Result<(), BrazaError> {
+        minting::add_minter(&env, &admin, &minter, quota)
+    }
 
-        let result = (|| {
-            let admin = storage::get_admin(&env);
-            admin.require_auth();
-            storage::bump_critical_storage(&env);
+    pub fn remove_minter(env: Env, admin: Address, minter: Address) -> Result<(), BrazaError> {
+        minting::remove_minter(&env, &admin, &minter)
+    }
 
-            validation::require_not_paused(&env)?;
-            validation::require_valid_vesting_params(
-                total_amount,
-                cliff_ledgers,
-                duration_ledgers,
-            )?;
-            validation::require_sufficient_balance(&env, &admin, total_amount)?;
-
-            let admin_balance = storage::get_balance(&env, &admin);
-            let new_admin_balance = admin_balance
-                .checked_sub(total_amount)
-                .ok_or(BrazaError::InsufficientBalance)?;
+    pub fn refill_minter_quota(
+        env: Env,
+        admin: Address,
+        minter: Address,
+        amount: i128,
+    ) -> Result<MinterInfo, BrazaError> {
+        minting::refill_minter_quota(&env, &admin, &minter, amount)
+    }
+
+    pub fn get_minter_info(env: Env, minter: Address) -> Result<MinterInfo, BrazaError> {
+        minting::get_minter_info(&env, &minter).ok_or(BrazaError::Unauthorized)
+    }
 
-            storage::set_balance(&env, &admin, new_admin_balance);
-            storage::increment_locked_balance(&env, total_amount)?;
+    pub fn is_minter(env: Env, minter: Address) -> bool {
+        minting::is_minter(&env, &minter)
+    }
 
-            let schedule_id = vesting::create_vesting_schedule(
-                &env,
-                &beneficiary,
-                total_amount,
-                cliff_ledgers,
-                duration_ledgers,
-                revocable,
-            )?;
+    // ========================================================================
+    // TAXA DE TRANSFERÊNCIA
+    // ========================================================================
 
-            events::emit_vesting_created(&env, &beneficiary, schedule_id, total_amount);
-            Ok(schedule_id)
-        })();
+    pub fn set_transfer_fee(
+        env: Env,
+        admin: Address,
+        config: TransferFeeConfig,
+    ) -> Result<(), BrazaError> {
+        fees::set_fee_config(&env, &admin, &config)
+    }
 
-        storage::set_reentrancy_guard(&env, false);
-        result
+    pub fn get_transfer_fee(env: Env) -> TransferFeeConfig {
+        storage::bump_critical_storage(&env);
+        fees::get_fee_config(&env)
     }
 
-    pub fn release_vested(
+    pub fn set_fee_exempt(
         env: Env,
-        beneficiary: Address,
-        schedule_id: u32,
-    ) -> Result<i128, BrazaError> {
-        if storage::is_reentrancy_locked(&env) {
-            return Err(BrazaError::Unauthorized);
+        admin: Address,
+        addr: Address,
+        exempt: bool,
+    ) -> Result<(), BrazaError> {
+        fees::set_fee_exempt(&env, &admin, &addr, exempt)
+    }
+
+    pub fn is_fee_exempt(env: Env, addr: Address) -> bool {
+        fees::is_fee_exempt(&env, &addr)
+    }
+
+    /// Valor que `to` receberia numa transferência de `amount` (bruto - taxa - queima).
+    pub fn get_net_amount(env: Env, from: Address, to: Address, amount: i128) -> i128 {
+        storage::bump_critical_storage(&env);
+        fees::split_transfer(&env, &from, &to, amount).map_or(0, |(net, _, _)| net)
+    }
+
+    pub fn get_total_fees_collected(env: Env) -> i128 {
+        storage::bump_critical_storage(&env);
+        fees::get_total_fees(&env)
+    }
+
+    /// Fração de cada transferência queimada (deflação), em basis points.
+    pub fn set_transfer_burn(env: Env, admin: Address, burn_bps: u32) -> Result<(), BrazaError> {
+        fees::set_transfer_burn_bps(&env, &admin, burn_bps)
+    }
+
+    pub fn get_transfer_burn(env: Env) -> u32 {
+        storage::bump_critical_storage(&env);
+        fees::get_transfer_burn_bps(&env)
+    }
+
+    // ========================================================================
+    // PROVA DE RESERVA
+    // ========================================================================
+
+    pub fn set_reserve_attester(
+        env: Env,
+        admin: Address,
+        attester: Address,
+        max_age_ledgers: u32,
+    ) -> Result<(), BrazaError> {
+        reserve::set_reserve_attester(&env, &admin, &attester, max_age_ledgers)
+    }
+
+    pub fn get_reserve_attester(env: Env) -> Option<Address> {
+        reserve::get_reserve_attester(&env)
+    }
+
+    pub fn attest_reserve(
+        env: Env,
+        attester: Address,
+        amount: i128,
+        report_hash: BytesN<32>,
+    ) -> Result<ReserveAttestation, BrazaError> {
+        reserve::attest_reserve(&env, &attester, amount, report_hash)
+    }
+
+    pub fn get_reserve_attestation(env: Env) -> Option<ReserveAttestation> {
+        reserve::get_attestation(&env)
+    }
+
+    /// Quanto ainda pode ser cunhado sob a reserva atestada (None = sem trava).
+    pub fn get_available_reserve(env: Env) -> Option<i128> {
+        reserve::available_reserve(&env)
+    }
+
+    // ============================================================================
+    // LIMITES DE CUNHAGEM
+    // ============================================================================
+
+    pub fn set_mint_limits(env: Env, admin: Address, limits: MintLimits) -> Result<(), BrazaError> {
+        minting::set_mint_limits(&env, &admin, &limits)
+    }
+
+    /// Afrouxar tetos de mint passa pelo timelock (`execute_mint_limits`).
+    pub fn propose_mint_limits(
+        env: Env,
+        admin: Address,
+        limits: MintLimits,
+    ) -> Result<MintLimitsProposal, BrazaError> {
+        minting::propose_mint_limits(&env, &admin, &limits)
+    }
+
+    pub fn execute_mint_limits(env: Env, admin: Address) -> Result<MintLimits, BrazaError> {
+        minting::execute_mint_limits(&env, &admin)
+    }
+
+    pub fn cancel_mint_limits(env: Env, admin: Address) -> Result<(), BrazaError> {
+        minting::cancel_mint_limits(&env, &admin)
+    }
+
+    pub fn get_pending_mint_limits(env: Env) -> Option<MintLimitsProposal> {
+        storage::bump_critical_storage(&env);
+        minting::get_mint_limits_proposal(&env)
+    }
+
+    pub fn get_mint_limits(env: Env) -> MintLimits {
+        minting::get_mint_limits(&env)
+    }
+
+    /// Quanto ainda pode ser cunhado na janela atual (todas as contas).
+    pub fn get_remaining_mint_allowance(env: Env) -> i128 {
+        minting::remaining_window_allowance(&env)
+    }
+
+    /// Quanto `minter` ainda pode cunhar na janela atual.
+    pub fn get_minter_allowance(env: Env, minter: Address) -> i128 {
+        minting::remaining_minter_allowance(&env, &minter)
+    }
+
+    // ============================================================================
+    // EMISSÃO PROGRAMADA (HALVINGS)
+    // ============================================================================
+
+    pub fn set_emission_config(
+        env: Env,
+        admin: Address,
+        config: EmissionConfig,
+    ) -> Result<(), BrazaError> {
+        emission::set_emission_config(&env, &admin, &config)
+    }
+
+    pub fn get_emission_config(env: Env) -> Result<EmissionConfig, BrazaError> {
+        emission::get_emission_config(&env).ok_or(BrazaError::EmissionNotConfigured)
+    }
+
+    /// Permissionless: cunha a emissão devida desde a última chamada.
+    pub fn emit(env: Env) -> Result<i128, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        storage::bump_critical_storage(&env);
+        let result = emission::emit(&env);
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    pub fn get_emission_epoch(env: Env) -> Result<u32, BrazaError> {
+        let config =
+            emission::get_emission_config(&env).ok_or(BrazaError::EmissionNotConfigured)?;
+        Ok(emission::epoch_at(&config, env.ledger().sequence()))
+    }
+
+    /// Tokens por ledger na época atual.
+    pub fn get_emission_rate(env: Env) -> Result<i128, BrazaError> {
+        let config =
+            emission::get_emission_config(&env).ok_or(BrazaError::EmissionNotConfigured)?;
+        let epoch = emission::epoch_at(&config, env.ledger().sequence());
+        Ok(emission::rate_for_epoch(&config, epoch))
+    }
+
+    pub fn get_next_halving(env: Env) -> Result<u32, BrazaError> {
+        let config =
+            emission::get_emission_config(&env).ok_or(BrazaError::EmissionNotConfigured)?;
+        Ok(emission::next_halving_ledger(
+            &config,
+            env.ledger().sequence(),
+        ))
+    }
+
+    pub fn get_pending_emission(env: Env) -> i128 {
+        emission::pending_emission(&env)
+    }
+
+    pub fn get_total_emitted(env: Env) -> i128 {
+        emission::get_total_emitted(&env)
+    }
+
+    // ============================================================================
+    // BURN (User)
+    // ============================================================================
+
+    pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        let result = (|| {
+            from.require_auth();
+
+            storage::bump_critical_storage(&env);
+            validation::require_not_paused(&env)?;
+            validation::require_positive_amount(amount)?;
+            validation::require_sufficient_balance(&env, &from, amount)?;
+
+            validation::require_not_blacklisted(&env, &from)?;
+            storage::validate_burn_not_locked(&env, amount)?;
+
+            let bal = storage::get_balance(&env, &from);
+            let new_bal = bal
+                .checked_sub(amount)
+                .ok_or(BrazaError::InsufficientBalance)?;
+
+            let supply = storage::get_total_supply(&env);
+            let new_supply = supply
+                .checked_sub(amount)
+                .ok_or(BrazaError::InvalidAmount)?;
+
+            storage::set_balance(&env, &from, new_bal);
+            storage::set_total_supply(&env, new_supply);
+            storage::add_total_burned(&env, amount);
+
+            events::emit_burn(&env, &from, amount);
+            Ok(())
+        })();
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    // ============================================================================
+    // VESTING
+    // ============================================================================
+
+    /// Vesting financiado pelo admin (compatibilidade).
+    pub fn create_vesting(
+        env: Env,
+        beneficiary: Address,
+        total_amount: i128,
+        cliff_ledgers: u32,
+        duration_ledgers: u32,
+        revocable: bool,
+    ) -> Result<u32, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        let admin = storage::get_admin(&env);
+        let result = execute_create_vesting(
+            &env,
+            &admin,
+            &beneficiary,
+            total_amount,
+            revocable,
+            ledger_terms(cliff_ledgers, duration_ledgers, VestingCurve::Linear),
+        );
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    /// Vesting financiado por um funder autorizado (tesouraria DAO, parceiros).
+    /// Tokens não vestidos voltam para o `funder` em caso de revogação.
+    pub fn create_vesting_funded(
+        env: Env,
+        funder: Address,
+        beneficiary: Address,
+        total_amount: i128,
+        cliff_ledgers: u32,
+        duration_ledgers: u32,
+        revocable: bool,
+    ) -> Result<u32, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        let result = execute_create_vesting(
+            &env,
+            &funder,
+            &beneficiary,
+            total_amount,
+            revocable,
+            ledger_terms(cliff_ledgers, duration_ledgers, VestingCurve::Linear),
+        );
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    /// Vesting com curva explícita (degraus, tabela de desbloqueio ou marcos).
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_vesting_with_curve(
+        env: Env,
+        funder: Address,
+        beneficiary: Address,
+        total_amount: i128,
+        cliff_ledgers: u32,
+        duration_ledgers: u32,
+        revocable: bool,
+        curve: VestingCurve,
+    ) -> Result<u32, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        let result = execute_create_vesting(
+            &env,
+            &funder,
+            &beneficiary,
+            total_amount,
+            revocable,
+            ledger_terms(cliff_ledgers, duration_ledgers, curve),
+        );
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    /// Vesting com termos completos: relógio por ledger ou por timestamp Unix.
+    /// Em `Timestamp`, `cliff`/`duration` são segundos (datas de contrato).
+    pub fn create_vesting_with_terms(
+        env: Env,
+        funder: Address,
+        beneficiary: Address,
+        total_amount: i128,
+        revocable: bool,
+        terms: VestingTerms,
+    ) -> Result<u32, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        let result =
+            execute_create_vesting(&env, &funder, &beneficiary, total_amount, revocable, terms);
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    /// Cria vários schedules financiados pelo mesmo `funder` (cap table no TGE).
+    /// O lote inteiro é validado antes (limites e saldo) e falha de forma atômica.
+    pub fn create_vesting_batch(
+        env: Env,
+        funder: Address,
+        grants: Vec<VestingGrant>,
+    ) -> Result<Vec<u32>, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        let result = (|| {
+            funder.require_auth();
+            storage::bump_critical_storage(&env);
+
+            validation::require_not_paused(&env)?;
+            validation::require_not_blacklisted(&env, &funder)?;
+            vesting::require_vesting_funder(&env, &funder)?;
+            vesting::validate_grant_batch(&env, &funder, &grants)?;
+
+            let mut ids = Vec::new(&env);
+            for grant in grants.iter() {
+                let id = fund_vesting(
+                    &env,
+                    &funder,
+                    &grant.beneficiary,
+                    grant.total_amount,
+                    grant.revocable,
+                    grant.terms,
+                )?;
+                ids.push_back(id);
+            }
+
+            env.events()
+                .publish((symbol_short!("v_batch"), &funder), ids.len());
+            Ok(ids)
+        })();
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    // ============================================================================
+    // FEE POOL DE VESTING
+    // ============================================================================
+
+    /// Saca taxas de storage acumuladas para `to` (ex.: tesouraria).
+    pub fn withdraw_vesting_fees(
+        env: Env,
+        admin: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), BrazaError> {
+        admin.require_auth();
+        storage::bump_critical_storage(&env);
+        validation::require_admin(&env, &admin)?;
+        validation::require_positive_amount(amount)?;
+        validation::require_not_blacklisted(&env, &to)?;
+
+        storage::withdraw_from_storage_fee_pool(&env, amount)?;
+        let bal = storage::get_balance(&env, &to);
+        let new_bal = bal.checked_add(amount).ok_or(BrazaError::OverflowError)?;
+        storage::set_balance(&env, &to, new_bal);
+
+        env.events()
+            .publish((symbol_short!("fee_wd"), &admin, &to), amount);
+        Ok(())
+    }
+
+    /// Queima taxas de storage acumuladas (reduz o total supply).
+    pub fn burn_vesting_fees(env: Env, admin: Address, amount: i128) -> Result<(), BrazaError> {
+        admin.require_auth();
+        storage::bump_critical_storage(&env);
+        validation::require_admin(&env, &admin)?;
+        validation::require_positive_amount(amount)?;
+
+        storage::withdraw_from_storage_fee_pool(&env, amount)?;
+        let supply = storage::get_total_supply(&env);
+        let new_supply = supply
+            .checked_sub(amount)
+            .ok_or(BrazaError::InvalidAmount)?;
+        storage::set_total_supply(&env, new_supply);
+        storage::add_total_burned(&env, amount);
+
+        env.events()
+            .publish((symbol_short!("fee_burn"), &admin), amount);
+        Ok(())
+    }
+
+    pub fn get_vesting_fee_pool(env: Env) -> i128 {
+        storage::get_vesting_stats(&env).fee_pool
+    }
+
+    /// Schedules ativos e teto global.
+    pub fn get_global_vesting_usage(env: Env) -> VestingUsage {
+        let stats = storage::get_vesting_stats(&env);
+        VestingUsage {
+            active_schedules: stats.active_schedules,
+            max_global_schedules: stats.max_global_schedules,
+        }
+    }
+
+    pub fn is_near_vesting_limit(env: Env) -> bool {
+        storage::is_near_global_vesting_limit(&env)
+    }
+
+    /// Ledgers restantes até `funder` poder criar outro schedule para o beneficiário.
+    pub fn get_vesting_cooldown(env: Env, funder: Address, beneficiary: Address) -> u32 {
+        storage::get_vesting_cooldown_remaining(&env, &funder, &beneficiary).unwrap_or(0)
+    }
+
+    /// Autoriza/desautoriza um endereço a financiar vesting (o admin sempre pode).
+    pub fn set_vesting_funder(
+        env: Env,
+        admin: Address,
+        funder: Address,
+        allowed: bool,
+    ) -> Result<(), BrazaError> {
+        vesting::set_vesting_funder(&env, &admin, &funder, allowed)
+    }
+
+    pub fn is_vesting_funder(env: Env, funder: Address) -> bool {
+        vesting::is_vesting_funder(&env, &funder)
+    }
+
+    /// Janela (em segundos) aceita para `start` retroativo ou futuro em `VestingTerms`.
+    pub fn set_vesting_start_limits(
+        env: Env,
+        admin: Address,
+        limits: VestingStartLimits,
+    ) -> Result<(), BrazaError> {
+        vesting::set_start_limits(&env, &admin, &limits)
+    }
+
+    pub fn get_vesting_start_limits(env: Env) -> VestingStartLimits {
+        vesting::get_start_limits(&env)
+    }
+
+    /// Admin atesta o marco `index` de um schedule por marcos.
+    pub fn attest_vesting_milestone(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        index: u32,
+    ) -> Result<i128, BrazaError> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+        storage::bump_critical_storage(&env);
+
+        let amount = vesting::attest_milestone(&env, &beneficiary, schedule_id, index)?;
+
+        env.events().publish(
+            (symbol_short!("v_mile"), &beneficiary, schedule_id),
+            (index, amount),
+        );
+        Ok(amount)
+    }
+
+    pub fn release_vested(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        beneficiary.require_auth();
+        let result = execute_release(&env, &beneficiary, schedule_id);
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    /// Libera de uma vez o vestido de todos os schedules do beneficiário.
+    /// Emite `v_rel` por schedule e `v_relall` com o total.
+    pub fn release_all_vested(env: Env, beneficiary: Address) -> Result<i128, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        let result = (|| {
+            beneficiary.require_auth();
+            storage::bump_critical_storage(&env);
+            validation::require_not_paused(&env)?;
+
+            let released = vesting::release_all_vested_tokens(&env, &beneficiary)?;
+
+            let mut total: i128 = 0;
+            for (schedule_id, amount) in released.iter() {
+                total = total.checked_add(amount).ok_or(BrazaError::OverflowError)?;
+                events::emit_vesting_released(&env, &beneficiary, schedule_id, amount);
+            }
+
+            let bal = storage::get_balance(&env, &beneficiary);
+            let new_bal = bal.checked_add(total).ok_or(BrazaError::InvalidAmount)?;
+
+            storage::set_balance(&env, &beneficiary, new_bal);
+            storage::decrement_locked_balance(&env, total)?;
+
+            env.events().publish(
+                (symbol_short!("v_relall"), &beneficiary),
+                (total, released.len()),
+            );
+            Ok(total)
+        })();
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    /// Arquiva um schedule concluído (totalmente liberado ou revogado), liberando o slot.
+    /// Permissionless: não move tokens, apenas compacta o estado.
+    pub fn archive_vesting(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<VestingSummary, BrazaError> {
+        storage::bump_critical_storage(&env);
+        let summary = vesting::archive_schedule(&env, &beneficiary, schedule_id)?;
+
+        env.events().publish(
+            (symbol_short!("v_arch"), &beneficiary, schedule_id),
+            summary.released_amount,
+        );
+        Ok(summary)
+    }
+
+    /// Arquiva de uma vez todos os schedules concluídos do beneficiário.
+    pub fn archive_completed_vestings(env: Env, beneficiary: Address) -> Vec<VestingSummary> {
+        storage::bump_critical_storage(&env);
+        let archived = vesting::archive_completed_schedules(&env, &beneficiary);
+
+        for summary in archived.iter() {
+            env.events().publish(
+                (symbol_short!("v_arch"), &beneficiary, summary.schedule_id),
+                summary.released_amount,
+            );
+        }
+        archived
+    }
+
+    /// Release feito pelo claimer delegado; os tokens vão sempre para o beneficiário.
+    pub fn release_vested_for(
+        env: Env,
+        claimer: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+
+        let result = (|| {
+            claimer.require_auth();
+            if vesting::get_claimer(&env, &beneficiary, schedule_id) != Some(claimer.clone()) {
+                return Err(BrazaError::Unauthorized);
+            }
+            execute_release(&env, &beneficiary, schedule_id)
+        })();
+
+        storage::set_reentrancy_guard(&env, false);
+        result
+    }
+
+    /// Define (ou remove, com `None`) o claimer delegado de um schedule.
+    pub fn set_vesting_claimer(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        claimer: Option<Address>,
+    ) -> Result<(), BrazaError> {
+        beneficiary.require_auth();
+        storage::bump_critical_storage(&env);
+
+        storage::get_vesting_schedule(&env, &beneficiary, schedule_id)
+            .ok_or(BrazaError::VestingNotFound)?;
+        vesting::set_claimer(&env, &beneficiary, schedule_id, claimer.clone());
+
+        env.events().publish(
+            (symbol_short!("v_claimr"), &beneficiary, schedule_id),
+            claimer,
+        );
+        Ok(())
+    }
+
+    pub fn get_vesting_claimer(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Option<Address> {
+        vesting::get_claimer(&env, &beneficiary, schedule_id)
+    }
+
+    /// Move um schedule para outro beneficiário (troca de chaves, custodiante).
+    /// Exige a assinatura das duas partes e, se configurado, do admin.
+    pub fn transfer_vesting(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        new_beneficiary: Address,
+    ) -> Result<u32, BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
         }
         storage::set_reentrancy_guard(&env, true);
 
         let result = (|| {
             beneficiary.require_auth();
+            new_beneficiary.require_auth();
+            if vesting::is_transfer_consent_required(&env) {
+                storage::get_admin(&env).require_auth();
+            }
             storage::bump_critical_storage(&env);
-            validation::require_not_paused(&env)?;
-
-            let releasable = vesting::release_vested_tokens(&env, &beneficiary, schedule_id)?;
 
-            let bal = storage::get_balance(&env, &beneficiary);
-            let new_bal = bal
-                .checked_add(releasable)
-                .ok_or(BrazaError::InvalidAmount)?;
+            validation::require_not_paused(&env)?;
+            validation::require_not_blacklisted(&env, &beneficiary)?;
+            validation::require_not_blacklisted(&env, &new_beneficiary)?;
 
-            storage::set_balance(&env, &beneficiary, new_bal);
-            storage::decrement_locked_balance(&env, releasable)?;
+            let new_id =
+                vesting::transfer_schedule(&env, &beneficiary, schedule_id, &new_beneficiary)?;
 
-            events::emit_vesting_released(&env, &beneficiary, schedule_id, releasable);
-            Ok(releasable)
+            env.events().publish(
+                (symbol_short!("v_move"), &beneficiary, schedule_id),
+                (new_beneficiary.clone(), new_id),
+            );
+            Ok(new_id)
         })();
 
         storage::set_reentrancy_guard(&env, false);
         result
     }
 
+    /// Admin habilita peso de voto/staking para os tokens travados de um schedule.
+    pub fn set_vesting_voting_weight(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        enabled: bool,
+    ) -> Result<(), BrazaError> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+        storage::bump_critical_storage(&env);
+
+        vesting::set_voting_weight(&env, &beneficiary, schedule_id, enabled)?;
+
+        env.events().publish(
+            (symbol_short!("v_vote"), &beneficiary, schedule_id),
+            enabled,
+        );
+        Ok(())
+    }
+
+    /// Peso de voto/staking: saldo livre + tokens travados em schedules elegíveis.
+    pub fn voting_power(env: Env, addr: Address) -> i128 {
+        storage::bump_critical_storage(&env);
+        storage::get_balance(&env, &addr)
+            .saturating_add(vesting::calculate_voting_vesting(&env, &addr))
+    }
+
+    /// Define se a revogação paga na hora o vestido não liberado (senão fica sacável).
+    pub fn set_vesting_revoke_payout(
+        env: Env,
+        admin: Address,
+        auto_release: bool,
+    ) -> Result<(), BrazaError> {
+        vesting::set_revoke_auto_release(&env, &admin, auto_release)
+    }
+
+    pub fn get_vesting_revoke_payout(env: Env) -> bool {
+        vesting::is_revoke_auto_release(&env)
+    }
+
+    /// Liga/desliga a exigência de assinatura do admin em `transfer_vesting`.
+    pub fn set_vesting_transfer_consent(
+        env: Env,
+        admin: Address,
+        required: bool,
+    ) -> Result<(), BrazaError> {
+        vesting::set_transfer_consent_required(&env, &admin, required)
+    }
+
+    pub fn get_vesting_transfer_consent(env: Env) -> bool {
+        vesting::is_transfer_consent_required(&env)
+    }
+
     pub fn revoke_vesting(
         env: Env,
         beneficiary: Address,
@@ -562,17 +1486,34 @@ impl BrazaToken {
             storage::bump_critical_storage(&env);
             validation::require_not_paused(&env)?;
 
+            let schedule = storage::get_vesting_schedule(&env, &beneficiary, schedule_id)
+                .ok_or(BrazaError::VestingNotFound)?;
             let unvested = vesting::revoke_vesting_schedule(&env, &beneficiary, schedule_id)?;
 
-            let adm_bal = storage::get_balance(&env, &admin);
-            let new_bal = adm_bal
+            let funder_bal = storage::get_balance(&env, &schedule.funder);
+            let new_bal = funder_bal
                 .checked_add(unvested)
                 .ok_or(BrazaError::InvalidAmount)?;
 
-            storage::set_balance(&env, &admin, new_bal);
+            storage::set_balance(&env, &schedule.funder, new_bal);
             storage::decrement_locked_balance(&env, unvested)?;
 
             events::emit_vesting_revoked(&env, &beneficiary, schedule_id);
+
+            // Vestido e não liberado: paga agora ou fica congelado e sacável
+            let revoked = storage::get_vesting_schedule(&env, &beneficiary, schedule_id)
+                .ok_or(BrazaError::VestingNotFound)?;
+            let claimable = vesting::calculate_releasable_amount(&env, &revoked);
+            if claimable > 0 {
+                if vesting::is_revoke_auto_release(&env) {
+                    execute_release(&env, &beneficiary, schedule_id)?;
+                } else {
+                    env.events().publish(
+                        (symbol_short!("v_frozen"), &beneficiary, schedule_id),
+                        claimable,
+                    );
+                }
+            }
             Ok(unvested)
         })();
 
@@ -610,6 +1551,45 @@ impl BrazaToken {
         Ok(vesting::calculate_releasable_amount(&env, &schedule))
     }
 
+    /// Schedules ativos do beneficiário (descontando slots arquivados/transferidos).
+    pub fn get_active_vesting_count(env: Env, beneficiary: Address) -> u32 {
+        storage::get_active_vesting_count(&env, &beneficiary)
+    }
+
+    /// Resumos arquivados do beneficiário, paginados a partir de `start`.
+    pub fn get_archived_vestings(
+        env: Env,
+        beneficiary: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<VestingSummary> {
+        storage::get_vesting_summaries(&env, &beneficiary, start, limit)
+    }
+
+    /// Quantidade de resumos arquivados do beneficiário.
+    pub fn get_archived_vesting_count(env: Env, beneficiary: Address) -> u32 {
+        storage::get_vesting_summary_count(&env, &beneficiary)
+    }
+
+    /// Soma do liberável agora em todos os schedules do beneficiário.
+    pub fn get_total_releasable(env: Env, beneficiary: Address) -> i128 {
+        storage::bump_critical_storage(&env);
+        vesting::calculate_total_releasable(&env, &beneficiary)
+    }
+
+    /// Início, cliff e fim do schedule em ledgers e em timestamps.
+    /// A representação do relógio do schedule é exata; a outra é estimada.
+    pub fn get_vesting_timeline(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<VestingTimeline, BrazaError> {
+        storage::bump_critical_storage(&env);
+        let schedule = storage::get_vesting_schedule(&env, &beneficiary, schedule_id)
+            .ok_or(BrazaError::VestingNotFound)?;
+        Ok(vesting::get_timeline(&schedule))
+    }
+
     pub fn get_locked_balance(env: Env) -> i128 {
         storage::bump_critical_storage(&env);
         storage::get_locked_balance(&env)
@@ -620,13 +1600,72 @@ impl BrazaToken {
         storage::get_circulating_supply(&env)
     }
 
-    pub fn get_supply_stats(env: Env) -> (i128, i128, i128, i128) {
+    pub fn get_total_burned(env: Env) -> i128 {
+        storage::bump_critical_storage(&env);
+        storage::get_total_burned(&env)
+    }
+
+    pub fn get_max_supply(env: Env) -> i128 {
+        storage::bump_critical_storage(&env);
+        storage::get_max_supply(&env)
+    }
+
+    pub fn get_initial_supply(env: Env) -> i128 {
+        storage::bump_critical_storage(&env);
+        storage::get_initial_supply(&env)
+    }
+
+    pub fn get_pending_max_supply(env: Env) -> Option<SupplyCapProposal> {
+        storage::bump_critical_storage(&env);
+        storage::get_max_supply_proposal(&env)
+    }
+
+    pub fn lower_max_supply(env: Env, admin: Address, new_max: i128) -> Result<(), BrazaError> {
+        use crate::admin;
+        admin::lower_max_supply(&env, &admin, new_max)
+    }
+
+    pub fn propose_max_supply_increase(
+        env: Env,
+        admin: Address,
+        new_max: i128,
+    ) -> Result<SupplyCapProposal, BrazaError> {
+        use crate::admin;
+        admin::propose_max_supply_increase(&env, &admin, new_max)
+    }
+
+    pub fn execute_max_supply_increase(env: Env, governance: Address) -> Result<i128, BrazaError> {
+        use crate::admin;
+        admin::execute_max_supply_increase(&env, &governance)
+    }
+
+    pub fn cancel_max_supply_increase(env: Env, admin: Address) -> Result<(), BrazaError> {
+        use crate::admin;
+        admin::cancel_max_supply_increase(&env, &admin)
+    }
+
+    pub fn set_governance(
+        env: Env,
+        caller: Address,
+        governance: Address,
+    ) -> Result<(), BrazaError> {
+        use crate::admin;
+        admin::set_governance(&env, &caller, &governance)
+    }
+
+    pub fn get_governance(env: Env) -> Option<Address> {
         storage::bump_critical_storage(&env);
-        let total = storage::get_total_supply(&env);
-        let locked = storage::get_locked_balance(&env);
-        let circulating = storage::get_circulating_supply(&env);
-        let max = storage::MAX_SUPPLY;
-        (total, locked, circulating, max)
+        storage::get_governance(&env)
+    }
+
+    pub fn get_supply_stats(env: Env) -> SupplyStats {
+        storage::bump_critical_storage(&env);
+        storage::get_supply_stats(&env)
+    }
+
+    pub fn get_vesting_stats(env: Env) -> VestingStats {
+        storage::bump_critical_storage(&env);
+        storage::get_vesting_stats(&env)
     }
 
     pub fn pause(env: Env) -> Result<(), BrazaError> {
@@ -705,6 +1744,11 @@ impl BrazaToken {
         current_admin.require_auth();
         storage::bump_critical_storage(&env);
 
+        // Admin e governança nunca podem ser a mesma chave
+        if storage::get_governance(&env) == Some(new_admin.clone()) {
+            return Err(BrazaError::Unauthorized);
+        }
+
         env.events()
             .publish((symbol_short!("adm_chg"), current_admin), new_admin.clone());
 
@@ -789,6 +1833,171 @@ impl BrazaToken {
         compliance::set_daily_limit(&env, &admin, &user, limit)
     }
 
+    pub fn set_daily_inflow_limit(
+        env: Env,
+        admin: Address,
+        user: Address,
+        limit: i128,
+    ) -> Result<(), BrazaError> {
+        compliance::set_daily_inflow_limit(&env, &admin, &user, limit)
+    }
+
+    pub fn set_velocity_rule(
+        env: Env,
+        admin: Address,
+        max_senders: u32,
+        block: bool,
+    ) -> Result<(), BrazaError> {
+        compliance::set_velocity_rule(&env, &admin, max_senders, block)
+    }
+
+    pub fn get_velocity_rule(env: Env) -> VelocityRule {
+        compliance::get_velocity_rule(&env)
+    }
+
+    pub fn get_daily_inflow(env: Env, user: Address) -> i128 {
+        compliance::get_daily_inflow(&env, &user)
+    }
+
+    pub fn get_daily_sender_count(env: Env, user: Address) -> u32 {
+        compliance::get_daily_sender_count(&env, &user)
+    }
+
+    pub fn set_corridor_rule(
+        env: Env,
+        admin: Address,
+        from_code: String,
+        to_code: String,
+        rule: CorridorRule,
+    ) -> Result<(), BrazaError> {
+        compliance::set_corridor_rule(&env, &admin, from_code, to_code, &rule)
+    }
+
+    pub fn remove_corridor_rule(
+        env: Env,
+        admin: Address,
+        from_code: String,
+        to_code: String,
+    ) -> Result<(), BrazaError> {
+        compliance::remove_corridor_rule(&env, &admin, from_code, to_code)
+    }
+
+    pub fn get_corridor_rule(env: Env, from_code: String, to_code: String) -> CorridorRule {
+        compliance::get_corridor_rule(&env, from_code, to_code)
+    }
+
+    pub fn is_corridor_allowed(env: Env, from_code: String, to_code: String) -> bool {
+        compliance::is_corridor_allowed(&env, from_code, to_code)
+    }
+
+    pub fn set_travel_rule_threshold(
+        env: Env,
+        admin: Address,
+        threshold: i128,
+    ) -> Result<(), BrazaError> {
+        compliance::set_travel_rule_threshold(&env, &admin, threshold)
+    }
+
+    pub fn get_travel_rule_threshold(env: Env) -> i128 {
+        compliance::get_travel_rule_threshold(&env)
+    }
+
+    // ============================================================================
+    // TETO POR TRANSAÇÃO & FILA DE RETENÇÃO
+    // ============================================================================
+
+    pub fn set_compliance_officer(env: Env, officer: Address) -> Result<(), BrazaError> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+        storage::bump_critical_storage(&env);
+
+        storage::set_compliance_officer(&env, &officer);
+        env.events().publish((symbol_short!("cmp_off"),), officer);
+        Ok(())
+    }
+
+    pub fn get_compliance_officer(env: Env) -> Option<Address> {
+        storage::bump_critical_storage(&env);
+        storage::get_compliance_officer(&env)
+    }
+
+    pub fn set_transfer_limits(
+        env: Env,
+        admin: Address,
+        limits: TransferLimits,
+    ) -> Result<(), BrazaError> {
+        pending::set_transfer_limits(&env, &admin, &limits)
+    }
+
+    pub fn get_transfer_limits(env: Env) -> TransferLimits {
+        storage::bump_critical_storage(&env);
+        pending::get_transfer_limits(&env)
+    }
+
+    pub fn approve_pending_transfer(
+        env: Env,
+        officer: Address,
+        pending_id: u32,
+    ) -> Result<(), BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+        let res = pending::approve_pending_transfer(&env, &officer, pending_id);
+        storage::set_reentrancy_guard(&env, false);
+        res
+    }
+
+    pub fn reject_pending_transfer(
+        env: Env,
+        officer: Address,
+        pending_id: u32,
+    ) -> Result<(), BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+        let res = pending::reject_pending_transfer(&env, &officer, pending_id);
+        storage::set_reentrancy_guard(&env, false);
+        res
+    }
+
+    pub fn release_pending_transfer(env: Env, pending_id: u32) -> Result<(), BrazaError> {
+        if storage::is_reentrancy_locked(&env) {
+            return Err(BrazaError::Unauthorized);
+        }
+        storage::set_reentrancy_guard(&env, true);
+        let res = pending::release_pending_transfer(&env, pending_id);
+        storage::set_reentrancy_guard(&env, false);
+        res
+    }
+
+    pub fn get_pending_transfer(env: Env, pending_id: u32) -> Result<PendingTransfer, BrazaError> {
+        storage::bump_critical_storage(&env);
+        pending::get_pending_transfer(&env, pending_id).ok_or(BrazaError::PendingTransferNotFound)
+    }
+
+    pub fn get_pending_memo(env: Env, pending_id: u32) -> Option<TravelRuleMemo> {
+        storage::bump_critical_storage(&env);
+        pending::get_pending_memo(&env, pending_id)
+    }
+
+    pub fn get_pending_transfers(env: Env, from: Address) -> Vec<PendingTransfer> {
+        storage::bump_critical_storage(&env);
+        pending::get_pending_transfers(&env, &from)
+    }
+
+    /// Total de ids de retenção emitidos (consultar cada um por id).
+    pub fn get_pending_transfer_count(env: Env) -> u32 {
+        storage::bump_critical_storage(&env);
+        pending::get_pending_count(&env)
+    }
+
+    pub fn get_total_held(env: Env) -> i128 {
+        storage::bump_critical_storage(&env);
+        pending::get_total_held(&env)
+    }
+
     // ✅ Função helper para bumpar storage (apenas para testes)
     // ✅ Função de contrato para bumpar storage (apenas para testes)
 }
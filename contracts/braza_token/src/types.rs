@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use soroban_sdk::{contracterror, contracttype, String};
+use soroban_sdk::{contracterror, contracttype, BytesN, String, Vec};
 
 // ============================================================================
 // ERROS DO CONTRATO
@@ -28,6 +28,24 @@ pub enum BrazaError {
     VestingAmountTooLow = 18,
     InsufficientAllowance = 19,
     OverflowError = 20, // ← ADICIONAR ESTA LINHA
+    TransferAmountExceeded = 21,
+    PendingTransferNotFound = 22,
+    TravelRuleMemoRequired = 23,
+    CorridorBlocked = 24,
+    VestingStartOutOfRange = 25,
+    VestingStillActive = 26,
+    EmissionNotConfigured = 27,
+    MintLimitExceeded = 28,
+    MinterQuotaExceeded = 29,
+    ReserveExceeded = 30,
+    ReserveAttestationStale = 31,
+    NoPendingSupplyChange = 32,
+    CorridorKycRequired = 33,
+    InflowLimitExceeded = 34,
+    VelocityLimitExceeded = 35,
+    MintLimitRaiseTimelocked = 36,
+    FeeExceedsAmount = 37,
+    GovernanceNotSet = 38,
 }
 
 // ============================================================================
@@ -42,6 +60,42 @@ pub struct TokenMetadata {
     pub decimals: u32,
 }
 
+// ============================================================================
+// CONFIGURAÇÃO DE INICIALIZAÇÃO
+// ============================================================================
+
+/// Alocação inicial (fundadores, comunidade, reserva...).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allocation {
+    pub recipient: soroban_sdk::Address,
+    pub amount: i128,
+}
+
+/// Política de compliance aplicada no deploy (`admin_country` vazio = admin sem país).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompliancePolicy {
+    pub admin_country: String,
+    pub admin_kyc_level: u32,
+    pub blocked_countries: Vec<String>,
+    pub travel_rule_threshold: i128,
+}
+
+/// Parâmetros do `initialize`. O supply inicial é a soma das alocações.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub max_supply: i128,
+    pub allocations: Vec<Allocation>,
+    pub compliance: CompliancePolicy,
+    pub compliance_officer: Option<soroban_sdk::Address>,
+    pub reserve_attester: Option<soroban_sdk::Address>,
+}
+
 // ============================================================================
 // VESTING SCHEDULE
 // ============================================================================
@@ -50,6 +104,7 @@ pub struct TokenMetadata {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VestingSchedule {
     pub beneficiary: soroban_sdk::Address,
+    pub funder: soroban_sdk::Address,
     pub total_amount: i128,
     pub released_amount: i128,
     pub start_ledger: u32,
@@ -57,6 +112,358 @@ pub struct VestingSchedule {
     pub duration_ledgers: u32,
     pub revocable: bool,
     pub revoked: bool,
+    // Vestido no momento da revogação (teto sacável); `total_amount` fica intacto
+    pub revoked_vested: i128,
+    pub curve: VestingCurve,
+    // Representação em tempo (segundos Unix). Em schedules `Ledger` é estimada
+    // na criação; em schedules `Timestamp` é a fonte da verdade.
+    pub clock: VestingClock,
+    pub start_time: u64,
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+    // Tokens ainda travados contam como peso de voto/staking do beneficiário
+    pub voting_weight: bool,
+}
+
+/// Registro compacto de um schedule arquivado (concluído ou revogado).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSummary {
+    pub schedule_id: u32,
+    pub funder: soroban_sdk::Address,
+    pub total_amount: i128,
+    pub released_amount: i128,
+    pub revoked: bool,
+    pub archived_ledger: u32,
+}
+
+/// Relógio que dirige o cálculo do vesting.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum VestingClock {
+    Ledger = 0,
+    Timestamp = 1,
+}
+
+/// Termos de criação de um vesting. `start`, `cliff` e `duration` na unidade do
+/// `clock` (ledgers ou segundos). `start = None` inicia no momento da criação.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingTerms {
+    pub clock: VestingClock,
+    pub start: Option<u64>,
+    pub cliff: u64,
+    pub duration: u64,
+    pub curve: VestingCurve,
+}
+
+// ============================================================================
+// LIMITES DE CUNHAGEM
+// ============================================================================
+
+/// Tetos de mint por janela de `window_ledgers` ledgers (0 = sem teto).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintLimits {
+    pub window_ledgers: u32,
+    pub max_per_window: i128,
+    pub max_per_minter: i128,
+}
+
+/// Afrouxamento de tetos de mint aguardando o timelock.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintLimitsProposal {
+    pub limits: MintLimits,
+    pub eta_ledger: u32,
+}
+
+/// Cota de um minter parceiro: total concedido, já usado e restante.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinterInfo {
+    pub quota: i128,
+    pub used: i128,
+    pub remaining: i128,
+}
+
+// ============================================================================
+// TETO DE SUPPLY (GOVERNANÇA)
+// ============================================================================
+
+/// Aumento de teto proposto; só executável a partir de `eta_ledger`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupplyCapProposal {
+    pub new_max_supply: i128,
+    pub eta_ledger: u32,
+}
+
+// ============================================================================
+// TAXA DE TRANSFERÊNCIA
+// ============================================================================
+
+/// Destino da taxa: tesouraria ou queima.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeDestination {
+    Burn,
+    Treasury(soroban_sdk::Address),
+}
+
+/// Taxa em basis points com piso e teto (`max_fee` 0 = sem teto; `fee_bps` 0 = desativada).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferFeeConfig {
+    pub fee_bps: u32,
+    pub min_fee: i128,
+    pub max_fee: i128,
+    pub destination: FeeDestination,
+}
+
+// ============================================================================
+// ESTATÍSTICAS (DASHBOARDS)
+// ============================================================================
+
+/// Visão consolidada do supply (substitui a antiga tupla de `get_supply_stats`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupplyStats {
+    pub total_supply: i128,
+    pub max_supply: i128,
+    pub locked_in_vesting: i128,
+    pub circulating_supply: i128,
+    pub total_minted: i128,
+    pub total_burned: i128,
+    pub force_burned: i128,
+    pub fee_pool: i128,
+    pub holders: u32,
+}
+
+/// Schedules ativos contra o teto global.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingUsage {
+    pub active_schedules: u32,
+    pub max_global_schedules: u32,
+}
+
+/// Ocupação global de vesting e pool de taxas de storage.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStats {
+    pub active_schedules: u32,
+    pub max_global_schedules: u32,
+    pub fee_pool: i128,
+    pub max_per_beneficiary: u32,
+    pub locked_balance: i128,
+}
+
+// ============================================================================
+// PROVA DE RESERVA
+// ============================================================================
+
+/// Última atestação de reserva (lastro em BRL) publicada pelo atestador.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveAttestation {
+    pub amount: i128,
+    pub attester: soroban_sdk::Address,
+    pub ledger: u32,
+    pub report_hash: BytesN<32>,
+}
+
+// ============================================================================
+// EMISSÃO PROGRAMADA (HALVINGS)
+// ============================================================================
+
+/// Destinatário da emissão e sua fatia em basis points (soma = 10_000).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionRecipient {
+    pub recipient: soroban_sdk::Address,
+    pub share_bps: u32,
+}
+
+/// Curva de emissão: `initial_rate` tokens por ledger na época 0,
+/// reduzido à metade a cada `halving_interval` ledgers contados de `start_ledger`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionConfig {
+    pub initial_rate: i128,
+    pub halving_interval: u32,
+    pub start_ledger: u32,
+    pub recipients: Vec<EmissionRecipient>,
+}
+
+/// Um grant de uma criação em lote (importação de cap table).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingGrant {
+    pub beneficiary: soroban_sdk::Address,
+    pub total_amount: i128,
+    pub revocable: bool,
+    pub terms: VestingTerms,
+}
+
+/// Janela aceita para o início de um vesting, em segundos relativos a agora.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStartLimits {
+    pub max_backdate_seconds: u64,
+    pub max_future_seconds: u64,
+}
+
+/// Marcos absolutos de um schedule nas duas representações.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingTimeline {
+    pub clock: VestingClock,
+    pub start_ledger: u32,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+    pub start_time: u64,
+    pub cliff_time: u64,
+    pub end_time: u64,
+}
+
+/// Ponto da tabela de desbloqueio: a partir de `ledger`, `cumulative_amount` está vestido.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnlockPoint {
+    pub ledger: u32,
+    pub cumulative_amount: i128,
+}
+
+/// Marco atestado pelo admin; `amount` é liberado quando `attested = true`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub amount: i128,
+    pub attested: bool,
+}
+
+/// Curva de liberação do vesting.
+/// - `Linear`: proporcional após o cliff (padrão)
+/// - `Periodic(period_ledgers)`: degraus a cada período após o cliff
+/// - `Custom(pontos)`: tabela (ledger, valor acumulado)
+/// - `Milestones(marcos)`: liberação por marcos atestados pelo admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VestingCurve {
+    Linear,
+    Periodic(u32),
+    Custom(Vec<UnlockPoint>),
+    Milestones(Vec<Milestone>),
+}
+
+// ============================================================================
+// REGRA DE VELOCIDADE (AML - RECEBIMENTO)
+// ============================================================================
+
+/// Limite de remetentes distintos que um endereço pode receber por dia.
+/// `max_senders = 0` desativa a regra. Com `block = false` apenas emite evento.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelocityRule {
+    pub max_senders: u32,
+    pub block: bool,
+}
+
+// ============================================================================
+// TETO POR TRANSAÇÃO E FILA DE RETENÇÃO
+// ============================================================================
+
+/// Limites por transferência. Valores `0` desativam a regra correspondente.
+/// `auto_release_ledgers` define após quantos ledgers a retenção pode ser liberada sem aprovação.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferLimits {
+    pub max_amount: i128,
+    pub hold_threshold: i128,
+    pub auto_release_ledgers: u32,
+}
+
+/// Transferência retida aguardando aprovação do compliance.
+/// O valor já foi debitado do remetente e fica em custódia no contrato.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingTransfer {
+    pub id: u32,
+    pub from: soroban_sdk::Address,
+    pub to: soroban_sdk::Address,
+    pub amount: i128,
+    pub created_ledger: u32,
+    pub release_ledger: u32,
+    pub has_memo: bool,
+    /// Valor já somado ao volume diário do remetente (estornado na rejeição)
+    pub counted_volume: bool,
+    /// Spender de um `transfer_from` retido (allowance devolvida na rejeição)
+    pub spender: Option<soroban_sdk::Address>,
+}
+
+// ============================================================================
+// TRAVEL RULE (FATF / BACEN)
+// ============================================================================
+
+/// Referência aos dados de originador/beneficiário mantidos off-chain.
+/// Somente o hash vai on-chain; VASPs são identificadores livres (ex: CNPJ, LEI).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TravelRuleMemo {
+    pub data_hash: BytesN<32>,
+    pub originator_vasp: Option<String>,
+    pub beneficiary_vasp: Option<String>,
+}
+
+// ============================================================================
+// CORREDORES (PAR DE PAÍSES)
+// ============================================================================
+
+/// Regra para transferências entre dois países (origem -> destino).
+/// `min_kyc` é exigido do remetente; `0` mantém o mínimo padrão.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CorridorRule {
+    pub blocked: bool,
+    pub min_kyc: u32,
+}
+
+// ============================================================================
+// PRÉ-CHECAGEM DE TRANSFERÊNCIA (estilo ERC-1404)
+// ============================================================================
+
+/// Código da regra que impediria a transferência. `Ok` = transferência passaria.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TransferRestriction {
+    Ok = 0,
+    Paused = 1,
+    InvalidAmount = 2,
+    InsufficientBalance = 3,
+    InsufficientAllowance = 4,
+    Blacklisted = 5,
+    KycInsufficient = 6,
+    RiskTooHigh = 7,
+    CountryNotAllowed = 8,
+    CorridorBlocked = 9,
+    CorridorKycRequired = 10,
+    DailyLimitExceeded = 11,
+    InflowLimitExceeded = 12,
+    VelocityLimitExceeded = 13,
+    TransferAmountExceeded = 14,
+    TravelRuleMemoRequired = 15,
+    FeeExceedsAmount = 16,
+}
+
+/// Resultado da pré-checagem: regra violada e a parte que a violou (se houver).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferCheck {
+    pub code: TransferRestriction,
+    pub party: Option<soroban_sdk::Address>,
 }
 
 // ============================================================================
@@ -118,6 +525,7 @@ mod tests {
 
         let s = VestingSchedule {
             beneficiary: addr.clone(),
+            funder: addr.clone(),
             total_amount: 1000,
             released_amount: 0,
             start_ledger: 0,
@@ -125,6 +533,13 @@ mod tests {
             duration_ledgers: 1000,
             revocable: true,
             revoked: false,
+            revoked_vested: 0,
+            curve: VestingCurve::Linear,
+            clock: VestingClock::Ledger,
+            start_time: 0,
+            cliff_seconds: 500,
+            duration_seconds: 5000,
+            voting_weight: false,
         };
 
         assert_eq!(s.clone(), s);
@@ -3,7 +3,7 @@
 #![allow(dead_code)]
 
 use crate::storage;
-use crate::types::BrazaError;
+use crate::types::{BrazaError, InitConfig, VestingClock, VestingCurve};
 use soroban_sdk::{Address, Env};
 // Importamos o módulo inteiro para delegar as verificações
 use crate::compliance;
@@ -64,17 +64,41 @@ pub fn require_max_supply_not_exceeded(env: &Env, add: i128) -> Result<(), Braza
     let new_sup = current
         .checked_add(add)
         .ok_or(BrazaError::MaxSupplyExceeded)?;
-    if new_sup > storage::MAX_SUPPLY {
+    if new_sup > storage::get_max_supply(env) {
         return Err(BrazaError::MaxSupplyExceeded);
     }
     Ok(())
 }
 
+/// Configuração de deploy válida; retorna o supply inicial (soma das alocações)
+pub fn require_valid_init_config(config: &InitConfig) -> Result<i128, BrazaError> {
+    if config.decimals > 18 || config.max_supply <= 0 {
+        return Err(BrazaError::InvalidAmount);
+    }
+    if config.compliance.admin_kyc_level > 3 || config.compliance.travel_rule_threshold < 0 {
+        return Err(BrazaError::InvalidAmount);
+    }
+
+    let mut initial: i128 = 0;
+    for a in config.allocations.iter() {
+        if a.amount <= 0 {
+            return Err(BrazaError::InvalidAmount);
+        }
+        initial = initial
+            .checked_add(a.amount)
+            .ok_or(BrazaError::OverflowError)?;
+    }
+    if initial > config.max_supply {
+        return Err(BrazaError::MaxSupplyExceeded);
+    }
+    Ok(initial)
+}
+
 /// Parâmetros de vesting válidos
 pub fn require_valid_vesting_params(
     total: i128,
-    cliff: u32,
-    duration: u32,
+    cliff: u64,
+    duration: u64,
 ) -> Result<(), BrazaError> {
     if total <= 0 {
         return Err(BrazaError::InvalidAmount);
@@ -96,6 +120,73 @@ pub fn require_valid_vesting_params(
     Ok(())
 }
 
+/// Par (funder, beneficiário) fora do cooldown de criação de vesting
+pub fn require_vesting_cooldown_expired(
+    env: &Env,
+    funder: &Address,
+    beneficiary: &Address,
+) -> Result<(), BrazaError> {
+    if !storage::is_vesting_cooldown_expired(env, funder, beneficiary) {
+        return Err(BrazaError::VestingCooldownActive);
+    }
+    Ok(())
+}
+
+/// Curva de vesting consistente com o total do schedule
+pub fn require_valid_vesting_curve(
+    total: i128,
+    duration: u64,
+    clock: VestingClock,
+    curve: &VestingCurve,
+) -> Result<(), BrazaError> {
+    match curve {
+        VestingCurve::Linear => Ok(()),
+        VestingCurve::Periodic(period) => {
+            if *period == 0 || *period as u64 > duration {
+                return Err(BrazaError::InvalidVestingParams);
+            }
+            Ok(())
+        }
+        VestingCurve::Custom(points) => {
+            // Tabela é expressa em ledgers absolutos
+            if clock != VestingClock::Ledger {
+                return Err(BrazaError::InvalidVestingParams);
+            }
+
+            // Ledgers crescentes, acumulado não-decrescente e terminando no total
+            let mut last_ledger = 0u32;
+            let mut last_amount = 0i128;
+            for (i, p) in points.iter().enumerate() {
+                if (i > 0 && p.ledger <= last_ledger)
+                    || p.cumulative_amount < last_amount
+                    || p.cumulative_amount <= 0
+                {
+                    return Err(BrazaError::InvalidVestingParams);
+                }
+                last_ledger = p.ledger;
+                last_amount = p.cumulative_amount;
+            }
+            if points.is_empty() || last_amount != total {
+                return Err(BrazaError::InvalidVestingParams);
+            }
+            Ok(())
+        }
+        VestingCurve::Milestones(milestones) => {
+            let mut sum = 0i128;
+            for m in milestones.iter() {
+                if m.amount <= 0 || m.attested {
+                    return Err(BrazaError::InvalidVestingParams);
+                }
+                sum = sum.checked_add(m.amount).ok_or(BrazaError::OverflowError)?;
+            }
+            if milestones.is_empty() || sum != total {
+                return Err(BrazaError::InvalidVestingParams);
+            }
+            Ok(())
+        }
+    }
+}
+
 //
 // NOVAS FUNÇÕES DE VALIDAÇÃO
 //
@@ -170,6 +261,7 @@ pub mod test_utils {
         require_acceptable_risk, require_admin, require_country_allowed,
         require_daily_volume_limit, require_kyc_level, require_max_supply_not_exceeded,
         require_not_blacklisted, require_not_paused, require_positive_amount,
-        require_sufficient_balance, require_valid_vesting_params, validate_kyc_level_value,
+        require_sufficient_balance, require_valid_vesting_curve, require_valid_vesting_params,
+        require_vesting_cooldown_expired, validate_kyc_level_value,
     };
 }
@@ -1,43 +1,94 @@
 use crate::storage;
-use crate::types::{BrazaError, VestingSchedule};
-use soroban_sdk::{symbol_short, Address, Env};
+use crate::types::{
+    BrazaError, VestingClock, VestingCurve, VestingGrant, VestingSchedule, VestingStartLimits,
+    VestingSummary, VestingTerms, VestingTimeline,
+};
+use crate::validation;
+use soroban_sdk::{symbol_short, Address, Env, Map, Vec};
 
 // ============================================================================
 // VESTING — LÓGICA MATEMÁTICA E DE ESTADO
 // ============================================================================
 
-/// Calcula quanto já deveria estar liberado até o ledger atual.
-/// Usa cálculo proporcional linear após o cliff.
+/// Calcula quanto já deveria estar liberado até o ledger (ou timestamp) atual.
+/// Delega para a curva do schedule (linear, degraus, tabela ou marcos).
 pub fn calculate_vested_amount(env: &Env, schedule: &VestingSchedule) -> i128 {
-    // Vesting revogado → não libera mais do que já foi liberado (congela no momento da revogação)
+    // Vesting revogado → congelado no vestido no momento da revogação;
+    // a parte ainda não liberada continua sacável
     if schedule.revoked {
-        return schedule.released_amount;
+        return schedule.revoked_vested;
     }
 
-    let now = env.ledger().sequence();
+    // (agora, início, cliff, duração) na unidade do relógio do schedule
+    let (now, start, cliff, duration) = match schedule.clock {
+        VestingClock::Ledger => (
+            env.ledger().sequence() as u64,
+            schedule.start_ledger as u64,
+            schedule.cliff_ledgers as u64,
+            schedule.duration_ledgers as u64,
+        ),
+        VestingClock::Timestamp => (
+            env.ledger().timestamp(),
+            schedule.start_time,
+            schedule.cliff_seconds,
+            schedule.duration_seconds,
+        ),
+    };
+    let elapsed = now.saturating_sub(start);
+
+    let vested = match &schedule.curve {
+        VestingCurve::Linear => linear_vested(schedule.total_amount, elapsed, cliff, duration, 1),
+        VestingCurve::Periodic(period) => linear_vested(
+            schedule.total_amount,
+            elapsed,
+            cliff,
+            duration,
+            *period as u64,
+        ),
+        VestingCurve::Custom(points) => {
+            // Maior valor acumulado cujo ledger já foi atingido
+            let mut vested = 0;
+            for p in points.iter() {
+                if p.ledger as u64 <= now {
+                    vested = p.cumulative_amount;
+                }
+            }
+            vested
+        }
+        VestingCurve::Milestones(milestones) => milestones
+            .iter()
+            .filter(|m| m.attested)
+            .fold(0i128, |acc, m| acc.saturating_add(m.amount)),
+    };
 
-    // Proteção contra underflow se o ledger atual for menor que o start (raro, mas possível em testes)
-    let elapsed = now.saturating_sub(schedule.start_ledger);
+    vested.min(schedule.total_amount)
+}
 
+/// Linear após o cliff, arredondando o tempo decorrido para baixo em múltiplos de `step`.
+/// `step = 1` é o vesting linear contínuo.
+/// `elapsed` já vem saturado em 0 caso o relógio esteja antes do início.
+fn linear_vested(total: i128, elapsed: u64, cliff: u64, duration: u64, step: u64) -> i128 {
     // Antes do cliff → 0 tokens
-    if elapsed < schedule.cliff_ledgers {
+    if elapsed < cliff {
         return 0;
     }
 
     // Após duration → 100% liberado
-    if elapsed >= schedule.duration_ledgers {
-        return schedule.total_amount;
+    if elapsed >= duration {
+        return total;
     }
 
+    let stepped = if step > 1 {
+        (elapsed / step) * step
+    } else {
+        elapsed
+    };
+
     // Cálculo linear proporcional
     // vested = (total_amount * elapsed) / duration
-    let num = schedule
-        .total_amount
-        .checked_mul(elapsed as i128)
-        .unwrap_or(0);
+    let num = total.checked_mul(stepped as i128).unwrap_or(0);
 
-    num.checked_div(schedule.duration_ledgers as i128)
-        .unwrap_or(0)
+    num.checked_div(duration as i128).unwrap_or(0)
 }
 
 /// Calcula quanto está disponível para release AGORA (Vested - Já Liberado)
@@ -46,23 +97,137 @@ pub fn calculate_releasable_amount(env: &Env, schedule: &VestingSchedule) -> i12
     vested.saturating_sub(schedule.released_amount).max(0)
 }
 
+/// Converte segundos em ledgers estimados (saturando em u32::MAX).
+fn seconds_to_ledgers(seconds: u64) -> u32 {
+    (seconds / storage::SECONDS_PER_LEDGER).min(u32::MAX as u64) as u32
+}
+
+/// Resolve o início (ledger, timestamp) a partir do `start` informado.
+/// Datas retroativas ou futuras precisam caber na janela configurada pelo admin.
+fn resolve_start(
+    env: &Env,
+    clock: VestingClock,
+    start: Option<u64>,
+) -> Result<(u32, u64), BrazaError> {
+    let now_ledger = env.ledger().sequence();
+    let now_time = env.ledger().timestamp();
+
+    let start = match start {
+        None => return Ok((now_ledger, now_time)),
+        Some(s) => s,
+    };
+
+    let limits = get_start_limits(env);
+    let check = |backdated: bool, offset_seconds: u64| {
+        let max = if backdated {
+            limits.max_backdate_seconds
+        } else {
+            limits.max_future_seconds
+        };
+        if offset_seconds > max {
+            return Err(BrazaError::VestingStartOutOfRange);
+        }
+        Ok(())
+    };
+
+    match clock {
+        VestingClock::Ledger => {
+            let start_ledger =
+                u32::try_from(start).map_err(|_| BrazaError::InvalidVestingParams)?;
+            if start_ledger <= now_ledger {
+                let back = (now_ledger - start_ledger) as u64 * storage::SECONDS_PER_LEDGER;
+                check(true, back)?;
+                Ok((start_ledger, now_time.saturating_sub(back)))
+            } else {
+                let ahead = (start_ledger - now_ledger) as u64 * storage::SECONDS_PER_LEDGER;
+                check(false, ahead)?;
+                Ok((start_ledger, now_time.saturating_add(ahead)))
+            }
+        }
+        VestingClock::Timestamp => {
+            if start <= now_time {
+                let back = now_time - start;
+                check(true, back)?;
+                Ok((now_ledger.saturating_sub(seconds_to_ledgers(back)), start))
+            } else {
+                let ahead = start - now_time;
+                check(false, ahead)?;
+                Ok((now_ledger.saturating_add(seconds_to_ledgers(ahead)), start))
+            }
+        }
+    }
+}
+
+/// Janela de início customizado. Padrão: zero (apenas início imediato).
+pub fn get_start_limits(env: &Env) -> VestingStartLimits {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("v_st_lim"))
+        .unwrap_or(VestingStartLimits {
+            max_backdate_seconds: 0,
+            max_future_seconds: 0,
+        })
+}
+
+pub fn set_start_limits(
+    env: &Env,
+    admin: &Address,
+    limits: &VestingStartLimits,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("v_st_lim"), limits);
+
+    env.events().publish(
+        (symbol_short!("vst_lim"), admin),
+        (limits.max_backdate_seconds, limits.max_future_seconds),
+    );
+    Ok(())
+}
+
 /// Cria um vesting schedule.
-/// Nota: Token.rs é responsável por debitar o saldo do criador e travar no locked_balance.
+/// Nota: Token.rs é responsável por debitar o saldo do `funder` e travar no locked_balance.
 pub fn create_vesting_schedule(
     env: &Env,
+    funder: &Address,
     beneficiary: &Address,
     total_amount: i128,
-    cliff_ledgers: u32,
-    duration_ledgers: u32,
     revocable: bool,
+    terms: VestingTerms,
 ) -> Result<u32, BrazaError> {
+    // Preenche as duas representações; a do relógio escolhido é a exata
+    let (cliff_ledgers, duration_ledgers, cliff_seconds, duration_seconds) = match terms.clock {
+        VestingClock::Ledger => {
+            let cliff = u32::try_from(terms.cliff).map_err(|_| BrazaError::InvalidVestingParams)?;
+            let duration =
+                u32::try_from(terms.duration).map_err(|_| BrazaError::InvalidVestingParams)?;
+            (
+                cliff,
+                duration,
+                terms.cliff.saturating_mul(storage::SECONDS_PER_LEDGER),
+                terms.duration.saturating_mul(storage::SECONDS_PER_LEDGER),
+            )
+        }
+        VestingClock::Timestamp => (
+            seconds_to_ledgers(terms.cliff),
+            seconds_to_ledgers(terms.duration),
+            terms.cliff,
+            terms.duration,
+        ),
+    };
+
+    let (start_ledger, start_time) = resolve_start(env, terms.clock, terms.start)?;
+
     // Incrementa contador e valida limites globais/usuário
     let schedule_id = storage::increment_vesting_count(env, beneficiary)?;
 
-    let start_ledger = env.ledger().sequence();
-
     let schedule = VestingSchedule {
         beneficiary: beneficiary.clone(),
+        funder: funder.clone(),
         total_amount,
         released_amount: 0,
         start_ledger,
@@ -70,6 +235,13 @@ pub fn create_vesting_schedule(
         duration_ledgers,
         revocable,
         revoked: false,
+        revoked_vested: 0,
+        curve: terms.curve,
+        clock: terms.clock,
+        start_time,
+        cliff_seconds,
+        duration_seconds,
+        voting_weight: false,
     };
 
     storage::set_vesting_schedule(env, beneficiary, schedule_id, &schedule);
@@ -93,11 +265,7 @@ pub fn release_vested_tokens(
     let mut schedule = storage::get_vesting_schedule(env, beneficiary, schedule_id)
         .ok_or(BrazaError::VestingNotFound)?;
 
-    // Se já foi revogado, não pode liberar mais nada além do que já foi pago
-    if schedule.revoked {
-        return Err(BrazaError::VestingNotFound);
-    }
-
+    // Revogados continuam liberando o vestido congelado na revogação
     let releasable = calculate_releasable_amount(env, &schedule);
 
     if releasable <= 0 {
@@ -115,8 +283,104 @@ pub fn release_vested_tokens(
     Ok(releasable)
 }
 
+/// Valida um lote de grants antes de qualquer escrita: parâmetros de cada grant,
+/// cooldown e limites por beneficiário, limite global, e saldo do `funder` para o
+/// total do lote (incluindo a taxa de storage de cada grant).
+pub fn validate_grant_batch(
+    env: &Env,
+    funder: &Address,
+    grants: &Vec<VestingGrant>,
+) -> Result<(), BrazaError> {
+    if grants.is_empty() {
+        return Err(BrazaError::InvalidVestingParams);
+    }
+
+    let global = storage::get_global_vesting_count(env);
+    if global.saturating_add(grants.len()) > storage::MAX_GLOBAL_VESTING_SCHEDULES {
+        return Err(BrazaError::GlobalVestingLimitExceeded);
+    }
+
+    let mut per_user: Map<Address, u32> = Map::new(env);
+    let mut total: i128 = 0;
+
+    for grant in grants.iter() {
+        validation::require_valid_vesting_params(
+            grant.total_amount,
+            grant.terms.cliff,
+            grant.terms.duration,
+        )?;
+        validation::require_valid_vesting_curve(
+            grant.total_amount,
+            grant.terms.duration,
+            grant.terms.clock,
+            &grant.terms.curve,
+        )?;
+
+        // Cooldown vale entre chamadas; dentro do lote o mesmo beneficiário pode repetir
+        let count = match per_user.get(grant.beneficiary.clone()) {
+            Some(c) => c,
+            None => {
+                validation::require_vesting_cooldown_expired(env, funder, &grant.beneficiary)?;
+                storage::get_active_vesting_count(env, &grant.beneficiary)
+            }
+        } + 1;
+        if count > storage::MAX_VESTING_SCHEDULES {
+            return Err(BrazaError::MaxVestingSchedulesExceeded);
+        }
+        per_user.set(grant.beneficiary.clone(), count);
+
+        total = total
+            .checked_add(grant.total_amount)
+            .and_then(|t| t.checked_add(storage::VESTING_STORAGE_FEE))
+            .ok_or(BrazaError::OverflowError)?;
+    }
+
+    validation::require_sufficient_balance(env, funder, total)
+}
+
+/// Libera o vestido de todos os schedules ativos do beneficiário.
+/// Retorna (schedule_id, valor) de cada schedule que liberou algo.
+pub fn release_all_vested_tokens(
+    env: &Env,
+    beneficiary: &Address,
+) -> Result<Vec<(u32, i128)>, BrazaError> {
+    let mut released = Vec::new(env);
+
+    for (id, mut schedule) in storage::get_all_vesting_schedules_with_ids(env, beneficiary).iter() {
+        let releasable = calculate_releasable_amount(env, &schedule);
+        if releasable <= 0 {
+            continue;
+        }
+
+        schedule.released_amount = schedule
+            .released_amount
+            .checked_add(releasable)
+            .ok_or(BrazaError::InvalidAmount)?;
+        storage::set_vesting_schedule(env, beneficiary, id, &schedule);
+
+        released.push_back((id, releasable));
+    }
+
+    if released.is_empty() {
+        return Err(BrazaError::NoTokensToRelease);
+    }
+    Ok(released)
+}
+
+/// Soma do liberável agora em todos os schedules do beneficiário.
+pub fn calculate_total_releasable(env: &Env, beneficiary: &Address) -> i128 {
+    storage::get_all_vesting_schedules(env, beneficiary)
+        .iter()
+        .fold(0i128, |acc, s| {
+            acc.saturating_add(calculate_releasable_amount(env, &s))
+        })
+}
+
 /// Revoga um vesting schedule.
-/// Retorna o valor "unvested" (que volta para o admin).
+/// Retorna o valor "unvested" (que volta para o `funder` do schedule).
+/// O vestido até agora fica em `revoked_vested` (o `total_amount` original é
+/// preservado): o que foi vestido e não liberado fica sacável pelo beneficiário
+/// (e segue no locked até o saque).
 pub fn revoke_vesting_schedule(
     env: &Env,
     beneficiary: &Address,
@@ -136,19 +400,291 @@ pub fn revoke_vesting_schedule(
     // Calcula o que o usuário tem direito até agora
     let vested = calculate_vested_amount(env, &schedule);
 
-    // O restante (Total - Vested) é o que será devolvido ao admin
+    // O restante (Total - Vested) é o que será devolvido ao funder
     let unvested = schedule.total_amount.saturating_sub(vested);
 
-    // Marca como revogado
+    // Marca como revogado e congela o vestido
     schedule.revoked = true;
-    // Opcional: Atualiza released_amount para igualar vested, para travar o estado visualmente
-    // schedule.released_amount = vested;
+    schedule.revoked_vested = vested;
 
     storage::set_vesting_schedule(env, beneficiary, schedule_id, &schedule);
 
     Ok(unvested)
 }
 
+/// Move um schedule para `new_beneficiary`, que recebe um novo id.
+/// O slot global é preservado (remove um, cria outro) e o claimer delegado é descartado.
+pub fn transfer_schedule(
+    env: &Env,
+    beneficiary: &Address,
+    schedule_id: u32,
+    new_beneficiary: &Address,
+) -> Result<u32, BrazaError> {
+    let mut schedule = storage::get_vesting_schedule(env, beneficiary, schedule_id)
+        .ok_or(BrazaError::VestingNotFound)?;
+
+    if schedule.revoked {
+        return Err(BrazaError::VestingNotFound);
+    }
+    if beneficiary == new_beneficiary {
+        return Err(BrazaError::InvalidVestingParams);
+    }
+
+    storage::release_vesting_slot(env, beneficiary, schedule_id);
+    set_claimer(env, beneficiary, schedule_id, None);
+
+    let new_id = storage::increment_vesting_count(env, new_beneficiary)?;
+    schedule.beneficiary = new_beneficiary.clone();
+    storage::set_vesting_schedule(env, new_beneficiary, new_id, &schedule);
+
+    Ok(new_id)
+}
+
+/// Liga/desliga o peso de voto/staking dos tokens travados de um schedule.
+pub fn set_voting_weight(
+    env: &Env,
+    beneficiary: &Address,
+    schedule_id: u32,
+    enabled: bool,
+) -> Result<(), BrazaError> {
+    let mut schedule = storage::get_vesting_schedule(env, beneficiary, schedule_id)
+        .ok_or(BrazaError::VestingNotFound)?;
+
+    schedule.voting_weight = enabled;
+    storage::set_vesting_schedule(env, beneficiary, schedule_id, &schedule);
+    Ok(())
+}
+
+/// Tokens ainda travados (não liberados) nos schedules com peso de voto.
+pub fn calculate_voting_vesting(env: &Env, beneficiary: &Address) -> i128 {
+    storage::get_all_vesting_schedules(env, beneficiary)
+        .iter()
+        .filter(|s| s.voting_weight)
+        .fold(0i128, |acc, s| {
+            acc.saturating_add(entitled_amount(&s).saturating_sub(s.released_amount))
+        })
+}
+
+/// Teto que o beneficiário ainda pode receber: o total, ou o vestido na revogação.
+pub fn entitled_amount(schedule: &VestingSchedule) -> i128 {
+    if schedule.revoked {
+        schedule.revoked_vested
+    } else {
+        schedule.total_amount
+    }
+}
+
+/// Schedule sem mais nada a liberar (totalmente pago; revogados após sacar o vestido).
+pub fn is_schedule_complete(schedule: &VestingSchedule) -> bool {
+    schedule.released_amount >= entitled_amount(schedule)
+}
+
+/// Arquiva um schedule concluído: grava o resumo compacto e libera o slot
+/// (id reaproveitável e contagem global decrementada).
+pub fn archive_schedule(
+    env: &Env,
+    beneficiary: &Address,
+    schedule_id: u32,
+) -> Result<VestingSummary, BrazaError> {
+    let schedule = storage::get_vesting_schedule(env, beneficiary, schedule_id)
+        .ok_or(BrazaError::VestingNotFound)?;
+
+    if !is_schedule_complete(&schedule) {
+        return Err(BrazaError::VestingStillActive);
+    }
+
+    let summary = VestingSummary {
+        schedule_id,
+        funder: schedule.funder,
+        total_amount: schedule.total_amount,
+        released_amount: schedule.released_amount,
+        revoked: schedule.revoked,
+        archived_ledger: env.ledger().sequence(),
+    };
+
+    storage::push_vesting_summary(env, beneficiary, &summary);
+    storage::release_vesting_slot(env, beneficiary, schedule_id);
+    set_claimer(env, beneficiary, schedule_id, None);
+
+    Ok(summary)
+}
+
+/// Arquiva todos os schedules concluídos do beneficiário. Retorna os resumos gerados.
+pub fn archive_completed_schedules(env: &Env, beneficiary: &Address) -> Vec<VestingSummary> {
+    let mut archived = Vec::new(env);
+    for (id, schedule) in storage::get_all_vesting_schedules_with_ids(env, beneficiary).iter() {
+        if is_schedule_complete(&schedule) {
+            if let Ok(summary) = archive_schedule(env, beneficiary, id) {
+                archived.push_back(summary);
+            }
+        }
+    }
+    archived
+}
+
+/// Se ativo, a revogação paga na hora o vestido não liberado ao beneficiário;
+/// caso contrário ele fica congelado e sacável via `release_vested`.
+pub fn is_revoke_auto_release(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("v_rv_pay"))
+        .unwrap_or(false)
+}
+
+pub fn set_revoke_auto_release(
+    env: &Env,
+    admin: &Address,
+    auto_release: bool,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("v_rv_pay"), &auto_release);
+
+    env.events()
+        .publish((symbol_short!("vrv_pay"), admin), auto_release);
+    Ok(())
+}
+
+/// Financiadores autorizados (tesouraria DAO, parceiros) além do admin.
+pub fn is_vesting_funder(env: &Env, funder: &Address) -> bool {
+    if validation::require_admin(env, funder).is_ok() {
+        return true;
+    }
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("v_fundr"), funder))
+        .unwrap_or(false)
+}
+
+pub fn set_vesting_funder(
+    env: &Env,
+    admin: &Address,
+    funder: &Address,
+    allowed: bool,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    let key = (symbol_short!("v_fundr"), funder);
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events()
+        .publish((symbol_short!("vfund_set"), funder), allowed);
+    Ok(())
+}
+
+/// Sem allowlist qualquer um lotaria os slots de um beneficiário com grants-poeira
+pub fn require_vesting_funder(env: &Env, funder: &Address) -> Result<(), BrazaError> {
+    if !is_vesting_funder(env, funder) {
+        return Err(BrazaError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Se ativo, `transfer_vesting` também exige a assinatura do admin.
+pub fn is_transfer_consent_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("v_tr_adm"))
+        .unwrap_or(false)
+}
+
+pub fn set_transfer_consent_required(
+    env: &Env,
+    admin: &Address,
+    required: bool,
+) -> Result<(), BrazaError> {
+    admin.require_auth();
+    storage::bump_critical_storage(env);
+    validation::require_admin(env, admin)?;
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("v_tr_adm"), &required);
+
+    env.events()
+        .publish((symbol_short!("vtr_cons"), admin), required);
+    Ok(())
+}
+
+/// Endereço autorizado a chamar o release em nome do beneficiário.
+pub fn get_claimer(env: &Env, beneficiary: &Address, schedule_id: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("v_claim"), beneficiary, schedule_id))
+}
+
+pub fn set_claimer(env: &Env, beneficiary: &Address, schedule_id: u32, claimer: Option<Address>) {
+    let key = (symbol_short!("v_claim"), beneficiary, schedule_id);
+    match claimer {
+        Some(c) => env.storage().persistent().set(&key, &c),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Marcos absolutos (início, fim do cliff, fim) em ledgers e em timestamps.
+pub fn get_timeline(schedule: &VestingSchedule) -> VestingTimeline {
+    VestingTimeline {
+        clock: schedule.clock,
+        start_ledger: schedule.start_ledger,
+        cliff_ledger: schedule.start_ledger.saturating_add(schedule.cliff_ledgers),
+        end_ledger: schedule
+            .start_ledger
+            .saturating_add(schedule.duration_ledgers),
+        start_time: schedule.start_time,
+        cliff_time: schedule.start_time.saturating_add(schedule.cliff_seconds),
+        end_time: schedule
+            .start_time
+            .saturating_add(schedule.duration_seconds),
+    }
+}
+
+/// Atesta um marco de um schedule `Milestones`.
+/// Retorna o valor do marco (passa a ser liberável).
+pub fn attest_milestone(
+    env: &Env,
+    beneficiary: &Address,
+    schedule_id: u32,
+    index: u32,
+) -> Result<i128, BrazaError> {
+    let mut schedule = storage::get_vesting_schedule(env, beneficiary, schedule_id)
+        .ok_or(BrazaError::VestingNotFound)?;
+
+    if schedule.revoked {
+        return Err(BrazaError::VestingNotFound);
+    }
+
+    let mut milestones = match schedule.curve {
+        VestingCurve::Milestones(m) => m,
+        _ => return Err(BrazaError::InvalidVestingParams),
+    };
+
+    let mut milestone = milestones
+        .get(index)
+        .ok_or(BrazaError::InvalidVestingParams)?;
+    if milestone.attested {
+        return Err(BrazaError::VestingAlreadyReleased);
+    }
+
+    milestone.attested = true;
+    let amount = milestone.amount;
+    milestones.set(index, milestone);
+    schedule.curve = VestingCurve::Milestones(milestones);
+
+    storage::set_vesting_schedule(env, beneficiary, schedule_id, &schedule);
+
+    Ok(amount)
+}
+
 // ============================================================================
 // MÓDULO DE TESTES (EXPORTADO)
 // ============================================================================
@@ -159,7 +695,11 @@ pub mod test_utils {
 
     // Re-exportar funções internas para testes de integração
     pub use super::{
-        calculate_releasable_amount, calculate_vested_amount, create_vesting_schedule,
-        release_vested_tokens, revoke_vesting_schedule,
+        archive_completed_schedules, archive_schedule, attest_milestone,
+        calculate_releasable_amount, calculate_total_releasable, calculate_vested_amount,
+        create_vesting_schedule, get_claimer, get_start_limits, get_timeline, is_schedule_complete,
+        is_transfer_consent_required, release_all_vested_tokens, release_vested_tokens,
+        revoke_vesting_schedule, set_claimer, set_start_limits, set_transfer_consent_required,
+        transfer_schedule, validate_grant_batch,
     };
 }
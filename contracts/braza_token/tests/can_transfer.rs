@@ -0,0 +1,121 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+use braza_token::types::{CorridorRule, TransferLimits, TransferRestriction};
+
+#[test]
+fn test_can_transfer_ok_matches_transfer() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &1000);
+
+    let check = t.client.can_transfer(&a, &b, &500);
+    assert_eq!(check.code, TransferRestriction::Ok);
+    assert_eq!(check.party, None);
+
+    assert!(t.client.try_transfer(&a, &b, &500).is_ok());
+}
+
+#[test]
+fn test_can_transfer_reports_offending_party() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &1000);
+
+    let check = t.client.can_transfer(&a, &b, &5000);
+    assert_eq!(check.code, TransferRestriction::InsufficientBalance);
+    assert_eq!(check.party, Some(a.clone()));
+
+    t.client.set_blacklisted(&b, &true);
+    let check = t.client.can_transfer(&a, &b, &100);
+    assert_eq!(check.code, TransferRestriction::Blacklisted);
+    assert_eq!(check.party, Some(b.clone()));
+    assert!(t.client.try_transfer(&a, &b, &100).is_err());
+
+    // Destinatário sem KYC/país
+    let unknown = Address::generate(&t.env);
+    let check = t.client.can_transfer(&a, &unknown, &100);
+    assert_eq!(check.code, TransferRestriction::KycInsufficient);
+    assert_eq!(check.party, Some(unknown));
+}
+
+#[test]
+fn test_can_transfer_limits_and_rules() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client.set_daily_limit(&t.admin, &a, &1000);
+    assert_eq!(
+        t.client.can_transfer(&a, &b, &1001).code,
+        TransferRestriction::DailyLimitExceeded
+    );
+
+    t.client.set_daily_inflow_limit(&t.admin, &b, &200);
+    assert_eq!(
+        t.client.can_transfer(&a, &b, &300).code,
+        TransferRestriction::InflowLimitExceeded
+    );
+
+    t.client.set_travel_rule_threshold(&t.admin, &100);
+    assert_eq!(
+        t.client.can_transfer(&a, &b, &150).code,
+        TransferRestriction::TravelRuleMemoRequired
+    );
+
+    let br = String::from_str(&t.env, "BR");
+    t.client.set_corridor_rule(
+        &t.admin,
+        &br,
+        &br,
+        &CorridorRule {
+            blocked: true,
+            min_kyc: 0,
+        },
+    );
+    assert_eq!(
+        t.client.can_transfer(&a, &b, &50).code,
+        TransferRestriction::CorridorBlocked
+    );
+}
+
+#[test]
+fn test_can_transfer_from_checks_allowance_and_max() {
+    let t = TestEnv::new();
+    let owner = t.create_compliant_user();
+    let spender = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&owner, &10_000);
+    t.client.approve(&owner, &spender, &1000);
+
+    let check = t.client.can_transfer_from(&spender, &owner, &b, &2000);
+    assert_eq!(check.code, TransferRestriction::InsufficientAllowance);
+    assert_eq!(check.party, Some(spender.clone()));
+
+    t.client.set_transfer_limits(
+        &t.admin,
+        &TransferLimits {
+            max_amount: 500,
+            hold_threshold: 0,
+            auto_release_ledgers: 0,
+        },
+    );
+    assert_eq!(
+        t.client.can_transfer_from(&spender, &owner, &b, &600).code,
+        TransferRestriction::TransferAmountExceeded
+    );
+    assert_eq!(
+        t.client.can_transfer_from(&spender, &owner, &b, &400).code,
+        TransferRestriction::Ok
+    );
+
+    // Pré-checagem não altera estado
+    assert_eq!(t.client.allowance(&owner, &spender), 1000);
+    assert_eq!(t.client.balance(&b), 0);
+}
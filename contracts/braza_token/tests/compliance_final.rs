@@ -8,7 +8,7 @@ use soroban_sdk::{
 };
 
 use braza_token::token::{BrazaToken, BrazaTokenClient};
-use braza_token::types::{BrazaError, VestingSchedule};
+use braza_token::types::{Allocation, BrazaError, CompliancePolicy, InitConfig, VestingSchedule};
 
 struct TestEnv<'a> {
     env: Env,
@@ -25,10 +25,29 @@ impl<'a> TestEnv<'a> {
         let client = BrazaTokenClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        let name = String::from_str(&env, "Braza Token");
-        let symbol = String::from_str(&env, "BRZ");
-
-        client.initialize(&admin, &name, &symbol);
+        let config = InitConfig {
+            name: String::from_str(&env, "Braza Token"),
+            symbol: String::from_str(&env, "BRZ"),
+            decimals: 7,
+            max_supply: braza_token::storage::MAX_SUPPLY,
+            allocations: soroban_sdk::vec![
+                &env,
+                Allocation {
+                    recipient: admin.clone(),
+                    amount: braza_token::storage::INITIAL_SUPPLY,
+                },
+            ],
+            compliance: CompliancePolicy {
+                admin_country: String::from_str(&env, "BR"),
+                admin_kyc_level: 3,
+                blocked_countries: Vec::new(&env),
+                travel_rule_threshold: 0,
+            },
+            compliance_officer: None,
+            reserve_attester: None,
+        };
+
+        client.initialize(&admin, &config);
 
         Self { env, client, admin }
     }
@@ -0,0 +1,127 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+use braza_token::types::{BrazaError, CorridorRule, TransferRestriction};
+
+fn rule(blocked: bool, min_kyc: u32) -> CorridorRule {
+    CorridorRule { blocked, min_kyc }
+}
+
+fn create_foreign_user(t: &TestEnv, code: &str) -> Address {
+    let user = Address::generate(&t.env);
+    t.client
+        .set_country_code(&t.admin, &user, &String::from_str(&t.env, code));
+    t.client.set_kyc_level(&t.admin, &user, &2);
+    t.client.set_risk_score(&t.admin, &user, &0);
+    user
+}
+
+#[test]
+fn test_blocked_corridor_rejects_transfer() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    let br = String::from_str(&t.env, "BR");
+    t.client
+        .set_corridor_rule(&t.admin, &br, &br, &rule(true, 0));
+    assert!(!t.client.is_corridor_allowed(&br, &br));
+
+    let res = t.client.try_transfer(&a, &b, &100);
+    assert_eq!(res, Err(Ok(BrazaError::CorridorBlocked)));
+
+    t.client.remove_corridor_rule(&t.admin, &br, &br);
+    assert!(t.client.is_corridor_allowed(&br, &br));
+    t.client.transfer(&a, &b, &100);
+}
+
+#[test]
+fn test_wildcard_corridor_requires_higher_kyc() {
+    let t = TestEnv::new();
+    let owner = t.create_compliant_user();
+    let spender = t.create_compliant_user();
+    let local = t.create_compliant_user();
+    let foreign = create_foreign_user(&t, "US");
+    t.client.mint(&owner, &10_000);
+    t.client.approve(&owner, &spender, &10_000);
+
+    let br = String::from_str(&t.env, "BR");
+    let any = String::from_str(&t.env, "*");
+    t.client
+        .set_corridor_rule(&t.admin, &br, &br, &rule(false, 0));
+    t.client
+        .set_corridor_rule(&t.admin, &br, &any, &rule(false, 3));
+
+    // BR->BR tem regra exata e continua livre
+    t.client.transfer_from(&spender, &owner, &local, &100);
+
+    // BR->US cai no coringa e exige KYC 3 do remetente
+    let res = t.client.try_transfer_from(&spender, &owner, &foreign, &100);
+    assert_eq!(res, Err(Ok(BrazaError::CorridorKycRequired)));
+
+    t.client.set_kyc_level(&t.admin, &owner, &3);
+    t.client.transfer_from(&spender, &owner, &foreign, &100);
+    assert_eq!(t.client.balance(&foreign), 100);
+}
+
+#[test]
+fn test_corridor_rule_resolution() {
+    let t = TestEnv::new();
+    let br = String::from_str(&t.env, "BR");
+    let us = String::from_str(&t.env, "US");
+    let any = String::from_str(&t.env, "*");
+
+    // Sem regras: só BR->BR é livre; qualquer par com outro país é negado
+    assert_eq!(t.client.get_corridor_rule(&br, &br), rule(false, 0));
+    assert_eq!(t.client.get_corridor_rule(&br, &us), rule(true, 0));
+    assert!(!t.client.is_corridor_allowed(&us, &br));
+
+    t.client
+        .set_corridor_rule(&t.admin, &us, &any, &rule(false, 2));
+    assert!(t.client.is_corridor_allowed(&us, &br));
+
+    t.client
+        .set_corridor_rule(&t.admin, &any, &us, &rule(true, 0));
+    assert!(!t.client.is_corridor_allowed(&br, &us));
+
+    let res = t
+        .client
+        .try_set_corridor_rule(&t.admin, &br, &us, &rule(false, 4));
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_transfer_to_foreign_country_follows_corridor() {
+    let t = TestEnv::new();
+    let local = t.create_compliant_user();
+    let foreign = create_foreign_user(&t, "US");
+    t.client.mint(&local, &10_000);
+
+    // Sem regra, BR->US é negado por padrão
+    let check = t.client.can_transfer(&local, &foreign, &100);
+    assert_eq!(check.code, TransferRestriction::CountryNotAllowed);
+    let res = t.client.try_transfer(&local, &foreign, &100);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    let br = String::from_str(&t.env, "BR");
+    let us = String::from_str(&t.env, "US");
+    t.client
+        .set_corridor_rule(&t.admin, &br, &us, &rule(false, 0));
+    t.client.transfer(&local, &foreign, &100);
+    assert_eq!(t.client.balance(&foreign), 100);
+
+    t.client
+        .set_corridor_rule(&t.admin, &br, &us, &rule(false, 3));
+    let check = t.client.can_transfer(&local, &foreign, &100);
+    assert_eq!(check.code, TransferRestriction::CorridorKycRequired);
+    let res = t.client.try_transfer(&local, &foreign, &100);
+    assert_eq!(res, Err(Ok(BrazaError::CorridorKycRequired)));
+
+    t.client.set_kyc_level(&t.admin, &local, &3);
+    t.client.transfer(&local, &foreign, &100);
+    assert_eq!(t.client.balance(&foreign), 200);
+}
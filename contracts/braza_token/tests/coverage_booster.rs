@@ -1,5 +1,6 @@
 #![cfg(test)]
 #![cfg(not(tarpaulin_include))]
+#![allow(clippy::bool_assert_comparison)]
 mod setup;
 use setup::TestEnv;
 use soroban_sdk::{testutils::Address as _, Address, String};
@@ -23,13 +24,16 @@ fn test_metadata_and_stats() {
     let total = t.client.total_supply();
     let locked = t.client.get_locked_balance();
     let circulating = t.client.get_circulating_supply();
-    let (s_total, s_locked, s_circ, s_max) = t.client.get_supply_stats();
-
-    assert_eq!(total, s_total);
-    assert_eq!(locked, s_locked);
-    assert_eq!(circulating, s_circ);
-    assert!(s_max > 0);
-    assert_eq!(s_total, s_circ + s_locked);
+    let stats = t.client.get_supply_stats();
+
+    assert_eq!(total, stats.total_supply);
+    assert_eq!(locked, stats.locked_in_vesting);
+    assert_eq!(circulating, stats.circulating_supply);
+    assert!(stats.max_supply > 0);
+    assert_eq!(
+        stats.total_supply,
+        stats.circulating_supply + stats.locked_in_vesting
+    );
 }
 
 // ============================================================================
@@ -0,0 +1,151 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Ledger, vec, Address};
+
+use braza_token::types::{BrazaError, EmissionConfig, EmissionRecipient, MintLimits};
+
+fn config(t: &TestEnv, pool: &Address, reserve: &Address) -> EmissionConfig {
+    EmissionConfig {
+        initial_rate: 1_000,
+        halving_interval: 100,
+        start_ledger: t.env.ledger().sequence(),
+        recipients: vec![
+            &t.env,
+            EmissionRecipient {
+                recipient: pool.clone(),
+                share_bps: 7_000,
+            },
+            EmissionRecipient {
+                recipient: reserve.clone(),
+                share_bps: 3_000,
+            },
+        ],
+    }
+}
+
+fn advance(t: &TestEnv, ledgers: u32) {
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += ledgers;
+    });
+}
+
+#[test]
+fn test_emission_splits_between_recipients() {
+    let t = TestEnv::new();
+    let pool = t.create_compliant_user();
+    let reserve = t.create_compliant_user();
+    t.client
+        .set_emission_config(&t.admin, &config(&t, &pool, &reserve));
+
+    let supply = t.client.total_supply();
+    advance(&t, 50);
+    assert_eq!(t.client.get_pending_emission(), 50_000);
+
+    assert_eq!(t.client.emit(), 50_000);
+    assert_eq!(t.client.balance(&pool), 35_000);
+    assert_eq!(t.client.balance(&reserve), 15_000);
+    assert_eq!(t.client.total_supply(), supply + 50_000);
+
+    // Chamar de novo no mesmo ledger não emite nada
+    assert_eq!(t.client.emit(), 0);
+}
+
+#[test]
+fn test_emission_halves_each_epoch() {
+    let t = TestEnv::new();
+    let pool = t.create_compliant_user();
+    let reserve = t.create_compliant_user();
+    let start = t.env.ledger().sequence();
+    t.client
+        .set_emission_config(&t.admin, &config(&t, &pool, &reserve));
+
+    assert_eq!(t.client.get_emission_epoch(), 0);
+    assert_eq!(t.client.get_emission_rate(), 1_000);
+    assert_eq!(t.client.get_next_halving(), start + 100);
+
+    // Atravessa duas halvings: 100*1000 + 100*500 + 50*250
+    advance(&t, 250);
+    assert_eq!(t.client.get_emission_epoch(), 2);
+    assert_eq!(t.client.get_emission_rate(), 250);
+    assert_eq!(t.client.get_next_halving(), start + 300);
+    assert_eq!(t.client.emit(), 162_500);
+    assert_eq!(t.client.get_total_emitted(), 162_500);
+}
+
+#[test]
+fn test_emission_config_validation() {
+    let t = TestEnv::new();
+    let pool = t.create_compliant_user();
+    let reserve = t.create_compliant_user();
+
+    assert_eq!(
+        t.client.try_emit(),
+        Err(Ok(BrazaError::EmissionNotConfigured))
+    );
+
+    let mut bad = config(&t, &pool, &reserve);
+    bad.recipients.pop_back();
+    let res = t.client.try_set_emission_config(&t.admin, &bad);
+    assert_eq!(res, Err(Ok(BrazaError::InvalidAmount)));
+
+    let res = t
+        .client
+        .try_set_emission_config(&pool, &config(&t, &pool, &reserve));
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+}
+
+#[test]
+fn test_reconfigure_is_not_retroactive() {
+    let t = TestEnv::new();
+    let pool = t.create_compliant_user();
+    let reserve = t.create_compliant_user();
+    t.client
+        .set_emission_config(&t.admin, &config(&t, &pool, &reserve));
+
+    advance(&t, 50);
+    assert_eq!(t.client.get_pending_emission(), 50_000);
+
+    // Taxa 10x maior só vale daqui pra frente; o acumulado fica congelado
+    let mut faster = config(&t, &pool, &reserve);
+    faster.initial_rate = 10_000;
+    t.client.set_emission_config(&t.admin, &faster);
+    assert_eq!(t.client.get_pending_emission(), 50_000);
+
+    advance(&t, 10);
+    assert_eq!(t.client.get_pending_emission(), 150_000);
+    assert_eq!(t.client.emit(), 150_000);
+    assert_eq!(t.client.get_pending_emission(), 0);
+}
+
+#[test]
+fn test_emission_counts_against_mint_window() {
+    let t = TestEnv::new();
+    let pool = t.create_compliant_user();
+    let reserve = t.create_compliant_user();
+    t.client.set_mint_limits(
+        &t.admin,
+        &MintLimits {
+            window_ledgers: 100,
+            max_per_window: 10_000,
+            max_per_minter: 0,
+        },
+    );
+    t.client
+        .set_emission_config(&t.admin, &config(&t, &pool, &reserve));
+
+    // 50_000 devidos, mas a janela só comporta 10_000; o resto fica pendente
+    advance(&t, 50);
+    assert_eq!(t.client.get_pending_emission(), 10_000);
+    assert_eq!(t.client.emit(), 10_000);
+    assert_eq!(t.client.get_pending_emission(), 0);
+    assert_eq!(
+        t.client.try_mint(&pool, &1),
+        Err(Ok(BrazaError::MintLimitExceeded))
+    );
+
+    advance(&t, 100);
+    assert_eq!(t.client.emit(), 10_000);
+    assert_eq!(t.client.get_total_emitted(), 20_000);
+}
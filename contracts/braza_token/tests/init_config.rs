@@ -0,0 +1,105 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+use braza_token::storage;
+use braza_token::token::{BrazaToken, BrazaTokenClient};
+use braza_token::types::{Allocation, BrazaError};
+
+fn alloc(recipient: &Address, amount: i128) -> Allocation {
+    Allocation {
+        recipient: recipient.clone(),
+        amount,
+    }
+}
+
+#[test]
+fn test_initialize_with_tokenomics_distribution() {
+    let t = TestEnv::new();
+    let contract_id = t.env.register_contract(None, BrazaToken);
+    let client = BrazaTokenClient::new(&t.env, &contract_id);
+
+    let admin = Address::generate(&t.env);
+    let founders = Address::generate(&t.env);
+    let community = Address::generate(&t.env);
+    let reserve = Address::generate(&t.env);
+    let officer = Address::generate(&t.env);
+    let attester = Address::generate(&t.env);
+
+    let mut config = setup::default_config(&t.env, &admin);
+    config.decimals = 2;
+    config.max_supply = 2_100;
+    config.allocations = vec![
+        &t.env,
+        alloc(&founders, 840),
+        alloc(&community, 1_050),
+        alloc(&reserve, 210),
+    ];
+    config.compliance.admin_country = String::from_str(&t.env, "BR");
+    config.compliance.blocked_countries = vec![&t.env, String::from_str(&t.env, "KP")];
+    config.compliance.travel_rule_threshold = 500;
+    config.compliance_officer = Some(officer.clone());
+    config.reserve_attester = Some(attester.clone());
+
+    client.initialize(&admin, &config);
+
+    assert_eq!(client.get_storage_version(), storage::STORAGE_VERSION);
+    assert_eq!(client.decimals(), 2);
+    assert_eq!(client.total_supply(), 2_100);
+    assert_eq!(client.get_initial_supply(), 2_100);
+    assert_eq!(client.balance(&founders), 840);
+    assert_eq!(client.balance(&community), 1_050);
+    assert_eq!(client.balance(&reserve), 210);
+    assert_eq!(client.balance(&admin), 0);
+
+    assert_eq!(client.get_travel_rule_threshold(), 500);
+    assert_eq!(client.get_compliance_officer(), Some(officer));
+    assert_eq!(client.get_reserve_attester(), Some(attester));
+
+    // Admin já nasce com país e KYC da política
+    let br = String::from_str(&t.env, "BR");
+    client.set_country_code(&admin, &community, &br);
+    client.set_kyc_level(&admin, &community, &2);
+    client.set_risk_score(&admin, &community, &0);
+    client.transfer(&community, &admin, &10);
+    assert_eq!(client.balance(&admin), 10);
+
+    // País bloqueado na política
+    client.set_country_code(&admin, &community, &String::from_str(&t.env, "KP"));
+    assert!(client.try_transfer(&community, &admin, &10).is_err());
+
+    let res = client.try_initialize(&admin, &config);
+    assert_eq!(res, Err(Ok(BrazaError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_initialize_rejects_invalid_config() {
+    let t = TestEnv::new();
+    let user = t.create_compliant_user();
+    let contract_id = t.env.register_contract(None, BrazaToken);
+    let client = BrazaTokenClient::new(&t.env, &contract_id);
+    let admin = Address::generate(&t.env);
+
+    let mut config = setup::default_config(&t.env, &admin);
+    config.decimals = 19;
+    assert_eq!(
+        client.try_initialize(&admin, &config),
+        Err(Ok(BrazaError::InvalidAmount))
+    );
+
+    let mut config = setup::default_config(&t.env, &admin);
+    config.allocations = vec![&t.env, alloc(&user, 0)];
+    assert_eq!(
+        client.try_initialize(&admin, &config),
+        Err(Ok(BrazaError::InvalidAmount))
+    );
+
+    let mut config = setup::default_config(&t.env, &admin);
+    config.compliance.admin_kyc_level = 4;
+    assert_eq!(
+        client.try_initialize(&admin, &config),
+        Err(Ok(BrazaError::InvalidAmount))
+    );
+}
@@ -1,5 +1,6 @@
 #![cfg(test)]
 #![cfg(not(tarpaulin_include))]
+#![allow(clippy::bool_assert_comparison)]
 mod setup;
 use setup::TestEnv;
 
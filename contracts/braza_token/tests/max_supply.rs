@@ -0,0 +1,147 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec, Address};
+
+use braza_token::storage;
+use braza_token::token::{BrazaToken, BrazaTokenClient};
+use braza_token::types::{Allocation, BrazaError};
+
+#[test]
+fn test_supply_config_set_at_initialize() {
+    let t = TestEnv::new();
+    assert_eq!(t.client.get_max_supply(), storage::MAX_SUPPLY);
+    assert_eq!(t.client.get_initial_supply(), storage::INITIAL_SUPPLY);
+
+    // Outro deploy com parâmetros próprios
+    let contract_id = t.env.register_contract(None, BrazaToken);
+    let other = BrazaTokenClient::new(&t.env, &contract_id);
+    let admin = Address::generate(&t.env);
+    let mut config = setup::default_config(&t.env, &admin);
+    config.max_supply = 1_000;
+    config.allocations = vec![
+        &t.env,
+        Allocation {
+            recipient: admin.clone(),
+            amount: 1_001,
+        },
+    ];
+
+    let res = other.try_initialize(&admin, &config);
+    assert_eq!(res, Err(Ok(BrazaError::MaxSupplyExceeded)));
+
+    config.allocations.set(
+        0,
+        Allocation {
+            recipient: admin.clone(),
+            amount: 400,
+        },
+    );
+    other.initialize(&admin, &config);
+    assert_eq!(other.get_max_supply(), 1_000);
+    assert_eq!(other.total_supply(), 400);
+    assert_eq!(other.balance(&admin), 400);
+}
+
+#[test]
+fn test_lower_max_supply() {
+    let t = TestEnv::new();
+    let user = t.create_compliant_user();
+    let supply = t.client.total_supply();
+
+    let res = t.client.try_lower_max_supply(&t.admin, &(supply - 1));
+    assert_eq!(res, Err(Ok(BrazaError::MaxSupplyExceeded)));
+    let res = t
+        .client
+        .try_lower_max_supply(&t.admin, &storage::MAX_SUPPLY);
+    assert_eq!(res, Err(Ok(BrazaError::InvalidAmount)));
+
+    t.client.lower_max_supply(&t.admin, &(supply + 100));
+    assert_eq!(t.client.get_max_supply(), supply + 100);
+
+    t.client.mint(&user, &100);
+    let res = t.client.try_mint(&user, &1);
+    assert_eq!(res, Err(Ok(BrazaError::MaxSupplyExceeded)));
+}
+
+#[test]
+fn test_raise_max_supply_requires_timelock() {
+    let t = TestEnv::new();
+    let governance = Address::generate(&t.env);
+    let new_max = storage::MAX_SUPPLY + 1_000;
+    t.client.set_governance(&t.admin, &governance);
+
+    let res = t.client.try_execute_max_supply_increase(&governance);
+    assert_eq!(res, Err(Ok(BrazaError::NoPendingSupplyChange)));
+
+    let proposal = t.client.propose_max_supply_increase(&t.admin, &new_max);
+    assert_eq!(t.client.get_pending_max_supply(), Some(proposal.clone()));
+
+    let res = t.client.try_execute_max_supply_increase(&governance);
+    assert_eq!(res, Err(Ok(BrazaError::TimelockNotExpired)));
+    assert_eq!(t.client.get_max_supply(), storage::MAX_SUPPLY);
+
+    // Avança em passos curtos, renovando o TTL da instância
+    while t.env.ledger().sequence() < proposal.eta_ledger {
+        t.env.ledger().with_mut(|info| {
+            info.sequence_number += 2_880;
+        });
+        t.client.get_max_supply();
+    }
+
+    // A mesma chave que propôs não executa
+    let res = t.client.try_execute_max_supply_increase(&t.admin);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    assert_eq!(t.client.execute_max_supply_increase(&governance), new_max);
+    assert_eq!(t.client.get_max_supply(), new_max);
+    assert_eq!(t.client.get_pending_max_supply(), None);
+}
+
+#[test]
+fn test_governance_is_separate_from_admin() {
+    let t = TestEnv::new();
+    let _ = t.create_compliant_user();
+    let governance = Address::generate(&t.env);
+    let next = Address::generate(&t.env);
+
+    // Sem governança configurada não há quem execute
+    t.client
+        .propose_max_supply_increase(&t.admin, &(storage::MAX_SUPPLY * 2));
+    let res = t.client.try_execute_max_supply_increase(&t.admin);
+    assert_eq!(res, Err(Ok(BrazaError::GovernanceNotSet)));
+
+    let res = t.client.try_set_governance(&t.admin, &t.admin);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    t.client.set_governance(&t.admin, &governance);
+    assert_eq!(t.client.get_governance(), Some(governance.clone()));
+
+    // Depois de definida, só a própria governança troca
+    let res = t.client.try_set_governance(&t.admin, &next);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+    t.client.set_governance(&governance, &next);
+    assert_eq!(t.client.get_governance(), Some(next.clone()));
+
+    // Admin não pode passar a ser a governança
+    let res = t.client.try_transfer_ownership(&next);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+}
+
+#[test]
+fn test_cancel_max_supply_increase() {
+    let t = TestEnv::new();
+    let _ = t.create_compliant_user();
+
+    let res = t
+        .client
+        .try_propose_max_supply_increase(&t.admin, &storage::MAX_SUPPLY);
+    assert_eq!(res, Err(Ok(BrazaError::InvalidAmount)));
+
+    t.client
+        .propose_max_supply_increase(&t.admin, &(storage::MAX_SUPPLY * 2));
+    t.client.cancel_max_supply_increase(&t.admin);
+    assert_eq!(t.client.get_pending_max_supply(), None);
+    assert!(t.client.try_cancel_max_supply_increase(&t.admin).is_err());
+}
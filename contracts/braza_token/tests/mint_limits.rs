@@ -0,0 +1,132 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::testutils::Ledger;
+
+use braza_token::types::{BrazaError, MintLimits};
+
+fn limits(window_ledgers: u32, max_per_window: i128, max_per_minter: i128) -> MintLimits {
+    MintLimits {
+        window_ledgers,
+        max_per_window,
+        max_per_minter,
+    }
+}
+
+#[test]
+fn test_mint_capped_per_window() {
+    let t = TestEnv::new();
+    let user = t.create_compliant_user();
+
+    t.client.set_mint_limits(&t.admin, &limits(100, 1_000, 0));
+    assert_eq!(t.client.get_remaining_mint_allowance(), 1_000);
+
+    t.client.mint(&user, &600);
+    assert_eq!(t.client.get_remaining_mint_allowance(), 400);
+
+    let res = t.client.try_mint(&user, &401);
+    assert_eq!(res, Err(Ok(BrazaError::MintLimitExceeded)));
+
+    // Nova janela zera o contador
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 100;
+    });
+    assert_eq!(t.client.get_remaining_mint_allowance(), 1_000);
+    t.client.mint(&user, &1_000);
+    assert_eq!(t.client.balance(&user), 1_600);
+}
+
+#[test]
+fn test_mint_capped_per_minter() {
+    let t = TestEnv::new();
+    let user = t.create_compliant_user();
+
+    t.client.set_mint_limits(&t.admin, &limits(100, 0, 500));
+    assert_eq!(t.client.get_remaining_mint_allowance(), i128::MAX);
+    assert_eq!(t.client.get_minter_allowance(&t.admin), 500);
+
+    t.client.mint(&user, &500);
+    assert_eq!(t.client.get_minter_allowance(&t.admin), 0);
+    assert_eq!(
+        t.client.try_mint(&user, &1),
+        Err(Ok(BrazaError::MintLimitExceeded))
+    );
+}
+
+#[test]
+fn test_mint_limits_validation() {
+    let t = TestEnv::new();
+    let user = t.create_compliant_user();
+
+    // Teto sem janela é inválido
+    let res = t.client.try_set_mint_limits(&t.admin, &limits(0, 1_000, 0));
+    assert_eq!(res, Err(Ok(BrazaError::InvalidAmount)));
+
+    let res = t.client.try_set_mint_limits(&user, &limits(100, 1_000, 0));
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    // Sem limites configurados o mint continua livre
+    t.client.mint(&user, &1_000_000);
+}
+
+#[test]
+fn test_lowering_caps_is_immediate() {
+    let t = TestEnv::new();
+    t.create_compliant_user();
+
+    t.client.set_mint_limits(&t.admin, &limits(100, 1_000, 500));
+    t.client.set_mint_limits(&t.admin, &limits(200, 800, 400));
+    assert_eq!(t.client.get_mint_limits(), limits(200, 800, 400));
+}
+
+#[test]
+fn test_raising_caps_requires_timelock() {
+    let t = TestEnv::new();
+    let user = t.create_compliant_user();
+    t.client.set_mint_limits(&t.admin, &limits(100, 1_000, 0));
+
+    // Subir, desligar ou encurtar a janela não vale na hora
+    for loose in [limits(100, 5_000, 0), limits(0, 0, 0), limits(10, 1_000, 0)] {
+        let res = t.client.try_set_mint_limits(&t.admin, &loose);
+        assert_eq!(res, Err(Ok(BrazaError::MintLimitRaiseTimelocked)));
+    }
+
+    let proposal = t
+        .client
+        .propose_mint_limits(&t.admin, &limits(100, 5_000, 0));
+    assert_eq!(t.client.get_pending_mint_limits(), Some(proposal.clone()));
+
+    // Antes do ETA o teto antigo continua valendo
+    let res = t.client.try_execute_mint_limits(&t.admin);
+    assert_eq!(res, Err(Ok(BrazaError::TimelockNotExpired)));
+    let res = t.client.try_mint(&user, &1_001);
+    assert_eq!(res, Err(Ok(BrazaError::MintLimitExceeded)));
+
+    // Avança em passos curtos, renovando o TTL da instância
+    while t.env.ledger().sequence() < proposal.eta_ledger {
+        t.env.ledger().with_mut(|info| {
+            info.sequence_number += 2_880;
+        });
+        t.client.get_mint_limits();
+    }
+
+    t.client.execute_mint_limits(&t.admin);
+    assert_eq!(t.client.get_mint_limits(), limits(100, 5_000, 0));
+    assert_eq!(t.client.get_pending_mint_limits(), None);
+    let fresh = t.create_compliant_user();
+    t.client.mint(&fresh, &5_000);
+}
+
+#[test]
+fn test_cancel_mint_limits_proposal() {
+    let t = TestEnv::new();
+    t.create_compliant_user();
+    t.client.set_mint_limits(&t.admin, &limits(100, 1_000, 0));
+
+    t.client.propose_mint_limits(&t.admin, &limits(0, 0, 0));
+    t.client.cancel_mint_limits(&t.admin);
+    assert_eq!(t.client.get_pending_mint_limits(), None);
+    let res = t.client.try_execute_mint_limits(&t.admin);
+    assert_eq!(res, Err(Ok(BrazaError::NoPendingSupplyChange)));
+}
@@ -0,0 +1,76 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Address as _, testutils::Events as _, Address, IntoVal, Symbol};
+
+use braza_token::types::{BrazaError, MinterInfo};
+
+#[test]
+fn test_minter_consumes_quota() {
+    let t = TestEnv::new();
+    let minter = Address::generate(&t.env);
+    let user = t.create_compliant_user();
+
+    t.client.add_minter(&t.admin, &minter, &1_000);
+    assert!(t.client.is_minter(&minter));
+
+    t.client.minter_mint(&minter, &user, &600);
+    assert_eq!(
+        t.client.get_minter_info(&minter),
+        MinterInfo {
+            quota: 1_000,
+            used: 600,
+            remaining: 400,
+        }
+    );
+
+    let res = t.client.try_minter_mint(&minter, &user, &401);
+    assert_eq!(res, Err(Ok(BrazaError::MinterQuotaExceeded)));
+
+    // Admin reabastece a cota
+    let info = t.client.refill_minter_quota(&t.admin, &minter, &500);
+    assert_eq!(info.remaining, 900);
+    t.client.minter_mint(&minter, &user, &900);
+    assert_eq!(t.client.balance(&user), 1_500);
+}
+
+#[test]
+fn test_unregistered_minter_rejected() {
+    let t = TestEnv::new();
+    let minter = Address::generate(&t.env);
+    let user = t.create_compliant_user();
+
+    let res = t.client.try_minter_mint(&minter, &user, &1);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    t.client.add_minter(&t.admin, &minter, &100);
+    t.client.remove_minter(&t.admin, &minter);
+    assert!(!t.client.is_minter(&minter));
+    assert!(t.client.try_get_minter_info(&minter).is_err());
+    assert!(t
+        .client
+        .try_refill_minter_quota(&t.admin, &minter, &100)
+        .is_err());
+}
+
+#[test]
+fn test_mint_event_includes_minter() {
+    let t = TestEnv::new();
+    let minter = Address::generate(&t.env);
+    let user = t.create_compliant_user();
+
+    t.client.add_minter(&t.admin, &minter, &1_000);
+    t.client.minter_mint(&minter, &user, &250);
+
+    let events = t.env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&t.env);
+    let emitted_minter: Address = topics.get(1).unwrap().into_val(&t.env);
+    let to: Address = topics.get(2).unwrap().into_val(&t.env);
+    let amount: i128 = data.into_val(&t.env);
+    assert_eq!(name, Symbol::new(&t.env, "mint"));
+    assert_eq!(emitted_minter, minter);
+    assert_eq!(to, user);
+    assert_eq!(amount, 250);
+}
@@ -0,0 +1,82 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, BytesN};
+
+use braza_token::types::BrazaError;
+
+fn report(t: &TestEnv) -> BytesN<32> {
+    BytesN::from_array(&t.env, &[9u8; 32])
+}
+
+#[test]
+fn test_mint_capped_by_attested_reserve() {
+    let t = TestEnv::new();
+    let attester = Address::generate(&t.env);
+    let user = t.create_compliant_user();
+    let supply = t.client.total_supply();
+
+    // Sem atestador configurado não há trava
+    assert_eq!(t.client.get_available_reserve(), None);
+    t.client.mint(&user, &100);
+
+    t.client.set_reserve_attester(&t.admin, &attester, &1_000);
+    let res = t.client.try_mint(&user, &1);
+    assert_eq!(res, Err(Ok(BrazaError::ReserveAttestationStale)));
+
+    let att = t
+        .client
+        .attest_reserve(&attester, &(supply + 1_100), &report(&t));
+    assert_eq!(t.client.get_reserve_attestation(), Some(att.clone()));
+    assert_eq!(att.attester, attester);
+    assert_eq!(t.client.get_available_reserve(), Some(1_000));
+
+    t.client.mint(&user, &1_000);
+    let res = t.client.try_mint(&user, &1);
+    assert_eq!(res, Err(Ok(BrazaError::ReserveExceeded)));
+}
+
+#[test]
+fn test_stale_attestation_blocks_mint() {
+    let t = TestEnv::new();
+    let attester = Address::generate(&t.env);
+    let user = t.create_compliant_user();
+    let supply = t.client.total_supply();
+
+    t.client.set_reserve_attester(&t.admin, &attester, &100);
+    t.client
+        .attest_reserve(&attester, &(supply + 10_000), &report(&t));
+    t.client.mint(&user, &500);
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 101;
+    });
+    assert_eq!(t.client.get_available_reserve(), Some(0));
+    let res = t.client.try_mint(&user, &500);
+    assert_eq!(res, Err(Ok(BrazaError::ReserveAttestationStale)));
+
+    t.client
+        .attest_reserve(&attester, &(supply + 10_000), &report(&t));
+    t.client.mint(&user, &500);
+    assert_eq!(t.client.balance(&user), 1_000);
+}
+
+#[test]
+fn test_only_attester_can_attest() {
+    let t = TestEnv::new();
+    let attester = Address::generate(&t.env);
+    let stranger = Address::generate(&t.env);
+
+    let res = t.client.try_attest_reserve(&attester, &1, &report(&t));
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    t.client.set_reserve_attester(&t.admin, &attester, &100);
+    assert_eq!(t.client.get_reserve_attester(), Some(attester.clone()));
+    let res = t.client.try_attest_reserve(&stranger, &1, &report(&t));
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+    assert!(t
+        .client
+        .try_set_reserve_attester(&t.admin, &attester, &0)
+        .is_err());
+}
@@ -1,10 +1,38 @@
 #![cfg(test)]
 #![cfg(not(tarpaulin_include))]
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{vec, Address, Env, String, Vec};
 // IMPORTANTE: Trazemos o Trait para o escopo para habilitar Address::generate()
 use soroban_sdk::testutils::Address as _;
 
+use braza_token::storage;
 use braza_token::token::{BrazaToken, BrazaTokenClient};
+use braza_token::types::{Allocation, CompliancePolicy, InitConfig};
+
+/// Configuração padrão de deploy: todo o supply inicial vai para o admin,
+/// que não recebe país (não transaciona até o compliance definir).
+pub fn default_config(env: &Env, admin: &Address) -> InitConfig {
+    InitConfig {
+        name: String::from_str(env, "Braza Token"),
+        symbol: String::from_str(env, "BRZ"),
+        decimals: 7,
+        max_supply: storage::MAX_SUPPLY,
+        allocations: vec![
+            env,
+            Allocation {
+                recipient: admin.clone(),
+                amount: storage::INITIAL_SUPPLY,
+            },
+        ],
+        compliance: CompliancePolicy {
+            admin_country: String::from_str(env, ""),
+            admin_kyc_level: 3,
+            blocked_countries: Vec::new(env),
+            travel_rule_threshold: 0,
+        },
+        compliance_officer: None,
+        reserve_attester: None,
+    }
+}
 
 pub struct TestEnv<'a> {
     pub env: Env,
@@ -12,6 +40,12 @@ pub struct TestEnv<'a> {
     pub admin: Address,
 }
 
+impl<'a> Default for TestEnv<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> TestEnv<'a> {
     pub fn new() -> Self {
         let env = Env::default();
@@ -27,11 +61,7 @@ impl<'a> TestEnv<'a> {
         let contract_id = env.register_contract(None, BrazaToken);
         let client = BrazaTokenClient::new(&env, &contract_id);
 
-        client.initialize(
-            &admin,
-            &String::from_str(&env, "Braza Token"),
-            &String::from_str(&env, "BRZ"),
-        );
+        client.initialize(&admin, &default_config(&env, &admin));
 
         Self { env, client, admin }
     }
@@ -0,0 +1,64 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+
+use braza_token::storage;
+
+#[test]
+fn test_supply_stats_track_mint_and_burn() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+
+    let stats = t.client.get_supply_stats();
+    assert_eq!(stats.total_supply, storage::INITIAL_SUPPLY);
+    assert_eq!(stats.max_supply, storage::MAX_SUPPLY);
+    assert_eq!(stats.total_minted, storage::INITIAL_SUPPLY);
+    assert_eq!(stats.total_burned, 0);
+    assert_eq!(stats.holders, 1);
+
+    t.client.mint(&a, &10_000);
+    t.client.transfer(&a, &b, &4_000);
+    t.client.burn(&a, &1_000);
+    t.client.force_burn(&b, &500);
+
+    let stats = t.client.get_supply_stats();
+    assert_eq!(stats.total_minted, storage::INITIAL_SUPPLY + 10_000);
+    assert_eq!(stats.total_burned, 1_500);
+    assert_eq!(stats.force_burned, 500);
+    assert_eq!(stats.total_supply, stats.total_minted - stats.total_burned);
+    assert_eq!(stats.circulating_supply, stats.total_supply);
+    assert_eq!(stats.holders, 3);
+
+    // Zerar o saldo remove o holder
+    t.client.burn(&b, &3_500);
+    assert_eq!(t.client.get_supply_stats().holders, 2);
+}
+
+#[test]
+fn test_vesting_stats_exposed() {
+    let t = TestEnv::new();
+    let user = t.create_compliant_user();
+    let grant: i128 = 100_000_000;
+
+    t.client.create_vesting(&user, &grant, &0, &1_000, &true);
+
+    let vesting = t.client.get_vesting_stats();
+    assert_eq!(vesting.active_schedules, 1);
+    assert_eq!(
+        vesting.max_global_schedules,
+        storage::MAX_GLOBAL_VESTING_SCHEDULES
+    );
+    assert_eq!(vesting.max_per_beneficiary, storage::MAX_VESTING_SCHEDULES);
+    assert_eq!(vesting.fee_pool, storage::VESTING_STORAGE_FEE);
+    assert_eq!(vesting.locked_balance, grant);
+
+    let supply = t.client.get_supply_stats();
+    assert_eq!(supply.locked_in_vesting, grant);
+    assert_eq!(supply.fee_pool, vesting.fee_pool);
+    assert_eq!(
+        supply.circulating_supply,
+        supply.total_supply - supply.locked_in_vesting
+    );
+}
@@ -0,0 +1,75 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Address as _, Address};
+
+use braza_token::types::{BrazaError, FeeDestination, TransferFeeConfig};
+
+#[test]
+fn test_transfer_burns_configured_fraction() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+    let supply = t.client.total_supply();
+
+    t.client.set_transfer_burn(&t.admin, &50);
+    assert_eq!(t.client.get_transfer_burn(), 50);
+    assert_eq!(t.client.get_net_amount(&a, &b, &2_000), 1_990);
+
+    t.client.transfer(&a, &b, &2_000);
+    assert_eq!(t.client.balance(&a), 8_000);
+    assert_eq!(t.client.balance(&b), 1_990);
+    assert_eq!(t.client.total_supply(), supply - 10);
+    assert_eq!(t.client.get_total_burned(), 10);
+
+    // Queima explícita entra no mesmo contador
+    t.client.burn(&b, &90);
+    assert_eq!(t.client.get_total_burned(), 100);
+}
+
+#[test]
+fn test_burn_combines_with_fee() {
+    let t = TestEnv::new();
+    let owner = t.create_compliant_user();
+    let spender = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let treasury = Address::generate(&t.env);
+    t.client.mint(&owner, &10_000);
+    t.client.approve(&owner, &spender, &10_000);
+
+    t.client.set_transfer_fee(
+        &t.admin,
+        &TransferFeeConfig {
+            fee_bps: 100,
+            min_fee: 0,
+            max_fee: 0,
+            destination: FeeDestination::Treasury(treasury.clone()),
+        },
+    );
+    t.client.set_transfer_burn(&t.admin, &200);
+
+    t.client.transfer_from(&spender, &owner, &b, &10_000);
+    assert_eq!(t.client.balance(&treasury), 100);
+    assert_eq!(t.client.balance(&b), 9_700);
+    assert_eq!(t.client.get_total_burned(), 200);
+
+    // Taxa + queima nunca passam de 100%
+    let res = t.client.try_set_transfer_burn(&t.admin, &9_901);
+    assert_eq!(res, Err(Ok(BrazaError::InvalidAmount)));
+}
+
+#[test]
+fn test_exempt_transfer_not_burned() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &1_000);
+
+    t.client.set_transfer_burn(&t.admin, &1_000);
+    t.client.set_fee_exempt(&t.admin, &b, &true);
+    t.client.transfer(&a, &b, &1_000);
+    assert_eq!(t.client.balance(&b), 1_000);
+    assert_eq!(t.client.get_total_burned(), 0);
+}
@@ -0,0 +1,176 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Address as _, testutils::Events as _, Address, IntoVal, Symbol};
+
+use braza_token::types::{
+    BrazaError, FeeDestination, TransferFeeConfig, TransferLimits, TransferRestriction,
+};
+
+fn fee(
+    fee_bps: u32,
+    min_fee: i128,
+    max_fee: i128,
+    destination: FeeDestination,
+) -> TransferFeeConfig {
+    TransferFeeConfig {
+        fee_bps,
+        min_fee,
+        max_fee,
+        destination,
+    }
+}
+
+#[test]
+fn test_fee_routed_to_treasury() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let treasury = Address::generate(&t.env);
+    t.client.mint(&a, &100_000);
+
+    // 1% com piso 50 e teto 500
+    t.client.set_transfer_fee(
+        &t.admin,
+        &fee(100, 50, 500, FeeDestination::Treasury(treasury.clone())),
+    );
+    assert_eq!(t.client.get_net_amount(&a, &b, &10_000), 9_900);
+    assert_eq!(t.client.get_net_amount(&a, &b, &1_000), 950);
+    assert_eq!(t.client.get_net_amount(&a, &b, &90_000), 89_500);
+
+    t.client.transfer(&a, &b, &10_000);
+    assert_eq!(t.client.balance(&a), 90_000);
+    assert_eq!(t.client.balance(&b), 9_900);
+    assert_eq!(t.client.balance(&treasury), 100);
+    assert_eq!(t.client.get_total_fees_collected(), 100);
+
+    // Evento de taxa precede o transfer líquido
+    let events = t.env.events().all();
+    let (_, topics, data) = events.get(events.len() - 2).unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&t.env);
+    let charged: i128 = data.into_val(&t.env);
+    assert_eq!(name, Symbol::new(&t.env, "xfer_fee"));
+    assert_eq!(charged, 100);
+    let (_, topics, data) = events.last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&t.env);
+    let net: i128 = data.into_val(&t.env);
+    assert_eq!(name, Symbol::new(&t.env, "transfer"));
+    assert_eq!(net, 9_900);
+}
+
+#[test]
+fn test_fee_burned_in_transfer_from() {
+    let t = TestEnv::new();
+    let owner = t.create_compliant_user();
+    let spender = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&owner, &10_000);
+    t.client.approve(&owner, &spender, &5_000);
+    let supply = t.client.total_supply();
+
+    t.client
+        .set_transfer_fee(&t.admin, &fee(200, 0, 0, FeeDestination::Burn));
+    t.client.transfer_from(&spender, &owner, &b, &5_000);
+
+    assert_eq!(t.client.balance(&owner), 5_000);
+    assert_eq!(t.client.balance(&b), 4_900);
+    assert_eq!(t.client.total_supply(), supply - 100);
+    assert_eq!(t.client.allowance(&owner, &spender), 0);
+}
+
+#[test]
+fn test_exempt_parties_pay_no_fee() {
+    let t = TestEnv::new();
+    let exchange = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&exchange, &10_000);
+
+    t.client
+        .set_transfer_fee(&t.admin, &fee(100, 10, 0, FeeDestination::Burn));
+    t.client.set_fee_exempt(&t.admin, &exchange, &true);
+    assert!(t.client.is_fee_exempt(&exchange));
+    assert!(t.client.is_fee_exempt(&t.client.address));
+
+    t.client.transfer(&exchange, &b, &1_000);
+    t.client.transfer(&b, &exchange, &1_000);
+    assert_eq!(t.client.balance(&exchange), 10_000);
+    assert_eq!(t.client.get_total_fees_collected(), 0);
+
+    t.client.set_fee_exempt(&t.admin, &exchange, &false);
+    t.client.transfer(&exchange, &b, &1_000);
+    assert_eq!(t.client.balance(&b), 990);
+}
+
+#[test]
+fn test_held_transfer_charged_on_settlement() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let treasury = Address::generate(&t.env);
+    t.client.mint(&a, &10_000);
+
+    t.client.set_transfer_fee(
+        &t.admin,
+        &fee(100, 0, 0, FeeDestination::Treasury(treasury.clone())),
+    );
+    t.client.set_transfer_limits(
+        &t.admin,
+        &TransferLimits {
+            max_amount: 0,
+            hold_threshold: 1_000,
+            auto_release_ledgers: 0,
+        },
+    );
+
+    t.client.transfer(&a, &b, &4_000);
+    assert_eq!(t.client.balance(&treasury), 0);
+
+    t.client.approve_pending_transfer(&t.admin, &0);
+    assert_eq!(t.client.balance(&b), 3_960);
+    assert_eq!(t.client.balance(&treasury), 40);
+}
+
+#[test]
+fn test_fee_config_validation() {
+    let t = TestEnv::new();
+    let _ = t.create_compliant_user();
+
+    let res = t
+        .client
+        .try_set_transfer_fee(&t.admin, &fee(10_001, 0, 0, FeeDestination::Burn));
+    assert_eq!(res, Err(Ok(BrazaError::InvalidAmount)));
+    let res = t
+        .client
+        .try_set_transfer_fee(&t.admin, &fee(100, 500, 100, FeeDestination::Burn));
+    assert_eq!(res, Err(Ok(BrazaError::InvalidAmount)));
+    assert_eq!(t.client.get_transfer_fee().fee_bps, 0);
+}
+
+#[test]
+fn test_fee_consuming_whole_amount_rejected() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let treasury = Address::generate(&t.env);
+    t.client.mint(&a, &10_000);
+
+    // Piso de 50: transferência de 50 não deixaria nada ao destinatário
+    t.client.set_transfer_fee(
+        &t.admin,
+        &fee(100, 50, 0, FeeDestination::Treasury(treasury.clone())),
+    );
+
+    let check = t.client.can_transfer(&a, &b, &50);
+    assert_eq!(check.code, TransferRestriction::FeeExceedsAmount);
+    let res = t.client.try_transfer(&a, &b, &50);
+    assert_eq!(res, Err(Ok(BrazaError::FeeExceedsAmount)));
+    assert_eq!(t.client.balance(&a), 10_000);
+
+    assert_eq!(
+        t.client.can_transfer(&a, &b, &51).code,
+        TransferRestriction::Ok
+    );
+    t.client.transfer(&a, &b, &51);
+    assert_eq!(t.client.balance(&b), 1);
+}
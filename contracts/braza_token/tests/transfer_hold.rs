@@ -0,0 +1,230 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::testutils::Ledger;
+
+use braza_token::types::{BrazaError, TransferLimits};
+
+fn limits(max_amount: i128, hold_threshold: i128, auto_release_ledgers: u32) -> TransferLimits {
+    TransferLimits {
+        max_amount,
+        hold_threshold,
+        auto_release_ledgers,
+    }
+}
+
+#[test]
+fn test_transfer_above_max_rejected() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client.set_transfer_limits(&t.admin, &limits(5000, 0, 0));
+
+    let res = t.client.try_transfer(&a, &b, &5001);
+    assert_eq!(res, Err(Ok(BrazaError::TransferAmountExceeded)));
+
+    t.client.transfer(&a, &b, &5000);
+    assert_eq!(t.client.balance(&b), 5000);
+}
+
+#[test]
+fn test_large_transfer_is_held_and_approved() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let officer = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client.set_compliance_officer(&officer);
+    t.client.set_transfer_limits(&t.admin, &limits(0, 1000, 0));
+
+    // Abaixo do limiar liquida normalmente
+    t.client.transfer(&a, &b, &1000);
+    assert_eq!(t.client.balance(&b), 1000);
+
+    // Acima do limiar fica retido (escrow)
+    t.client.transfer(&a, &b, &3000);
+    assert_eq!(t.client.balance(&a), 6000);
+    assert_eq!(t.client.balance(&b), 1000);
+    assert_eq!(t.client.get_total_held(), 3000);
+
+    let pending = t.client.get_pending_transfers(&a);
+    assert_eq!(pending.len(), 1);
+    let p = pending.get(0).unwrap();
+    assert_eq!(p.amount, 3000);
+    assert_eq!(p.to, b);
+
+    t.client.approve_pending_transfer(&officer, &p.id);
+    assert_eq!(t.client.balance(&b), 4000);
+    assert_eq!(t.client.get_total_held(), 0);
+    assert_eq!(t.client.get_pending_transfers(&a).len(), 0);
+    assert!(t.client.try_get_pending_transfer(&p.id).is_err());
+}
+
+#[test]
+fn test_rejected_transfer_refunds_sender() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let stranger = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client.set_transfer_limits(&t.admin, &limits(0, 1000, 0));
+    t.client.transfer(&a, &b, &2500);
+
+    // Somente admin ou compliance officer decidem
+    let res = t.client.try_reject_pending_transfer(&stranger, &0);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    t.client.reject_pending_transfer(&t.admin, &0);
+    assert_eq!(t.client.balance(&a), 10_000);
+    assert_eq!(t.client.balance(&b), 0);
+    assert_eq!(t.client.get_total_held(), 0);
+}
+
+#[test]
+fn test_pending_transfer_auto_release() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client
+        .set_transfer_limits(&t.admin, &limits(0, 1000, 100));
+    t.client.transfer(&a, &b, &2000);
+
+    let res = t.client.try_release_pending_transfer(&0);
+    assert_eq!(res, Err(Ok(BrazaError::TimelockNotExpired)));
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 100;
+    });
+
+    t.client.release_pending_transfer(&0);
+    assert_eq!(t.client.balance(&b), 2000);
+}
+
+#[test]
+fn test_transfer_from_above_threshold_is_held() {
+    let t = TestEnv::new();
+    let owner = t.create_compliant_user();
+    let spender = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&owner, &10_000);
+    t.client.approve(&owner, &spender, &5000);
+
+    t.client.set_transfer_limits(&t.admin, &limits(0, 1000, 0));
+    t.client.transfer_from(&spender, &owner, &b, &2000);
+
+    assert_eq!(t.client.balance(&b), 0);
+    assert_eq!(t.client.allowance(&owner, &spender), 3000);
+    assert_eq!(t.client.get_pending_transfer(&0).amount, 2000);
+}
+
+#[test]
+fn test_reject_held_transfer_from_restores_allowance() {
+    let t = TestEnv::new();
+    let owner = t.create_compliant_user();
+    let spender = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&owner, &10_000);
+    t.client.approve(&owner, &spender, &5000);
+
+    t.client.set_transfer_limits(&t.admin, &limits(0, 1000, 0));
+    t.client.transfer_from(&spender, &owner, &b, &2000);
+    assert_eq!(t.client.allowance(&owner, &spender), 3000);
+    assert_eq!(
+        t.client.get_pending_transfer(&0).spender,
+        Some(spender.clone())
+    );
+
+    t.client.reject_pending_transfer(&t.admin, &0);
+    assert_eq!(t.client.balance(&owner), 10_000);
+    assert_eq!(t.client.allowance(&owner, &spender), 5000);
+}
+
+#[test]
+fn test_settlement_rechecks_sender_compliance() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client
+        .set_transfer_limits(&t.admin, &limits(0, 1000, 100));
+    t.client.transfer(&a, &b, &2000);
+    t.client.transfer(&a, &b, &2000);
+    assert_eq!(t.client.get_pending_transfer_count(), 2);
+
+    // Remetente passa a ser de alto risco durante a retenção
+    t.client.set_risk_score(&t.admin, &a, &60);
+    let res = t.client.try_approve_pending_transfer(&t.admin, &0);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 100;
+    });
+    let res = t.client.try_release_pending_transfer(&1);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    // Bloqueado na blacklist também não liquida
+    t.client.set_risk_score(&t.admin, &a, &0);
+    t.client.set_blacklisted(&a, &true);
+    let res = t.client.try_approve_pending_transfer(&t.admin, &0);
+    assert_eq!(res, Err(Ok(BrazaError::Blacklisted)));
+
+    t.client.set_blacklisted(&a, &false);
+    t.client.approve_pending_transfer(&t.admin, &0);
+    t.client.release_pending_transfer(&1);
+    assert_eq!(t.client.balance(&b), 4000);
+}
+
+#[test]
+fn test_approve_honors_pause_like_release() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client.set_transfer_limits(&t.admin, &limits(0, 1000, 10));
+    t.client.transfer(&a, &b, &2000);
+    t.client.transfer(&a, &b, &2000);
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 10;
+    });
+
+    t.client.pause();
+    let res = t.client.try_approve_pending_transfer(&t.admin, &0);
+    assert_eq!(res, Err(Ok(BrazaError::Paused)));
+    let res = t.client.try_release_pending_transfer(&0);
+    assert_eq!(res, Err(Ok(BrazaError::Paused)));
+
+    // Rejeição só devolve ao remetente e segue permitida
+    t.client.reject_pending_transfer(&t.admin, &1);
+    assert_eq!(t.client.balance(&a), 8000);
+
+    t.client.unpause();
+    t.client.approve_pending_transfer(&t.admin, &0);
+    assert_eq!(t.client.balance(&b), 2000);
+}
+
+#[test]
+fn test_reject_refunds_daily_volume() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client.set_daily_limit(&t.admin, &a, &3000);
+    t.client.set_transfer_limits(&t.admin, &limits(0, 1000, 0));
+    t.client.transfer(&a, &b, &2500);
+    t.client.reject_pending_transfer(&t.admin, &0);
+
+    // Sem o estorno, 2500 + 2500 estouraria o limite diário de 3000
+    t.client.transfer(&a, &b, &2500);
+    assert_eq!(t.client.get_pending_transfer(&1).amount, 2500);
+    assert_eq!(t.client.get_pending_transfer_count(), 2);
+}
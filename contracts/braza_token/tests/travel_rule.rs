@@ -0,0 +1,108 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Events as _, BytesN, IntoVal, String, Symbol};
+
+use braza_token::types::{BrazaError, TransferLimits, TravelRuleMemo};
+
+fn memo(t: &TestEnv) -> TravelRuleMemo {
+    TravelRuleMemo {
+        data_hash: BytesN::from_array(&t.env, &[7u8; 32]),
+        originator_vasp: Some(String::from_str(&t.env, "VASP-BR-001")),
+        beneficiary_vasp: None,
+    }
+}
+
+#[test]
+fn test_memo_required_above_threshold() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client.set_travel_rule_threshold(&t.admin, &1000);
+    assert_eq!(t.client.get_travel_rule_threshold(), 1000);
+
+    // Abaixo do limiar não exige memo
+    t.client.transfer(&a, &b, &1000);
+
+    let res = t.client.try_transfer(&a, &b, &1001);
+    assert_eq!(res, Err(Ok(BrazaError::TravelRuleMemoRequired)));
+
+    t.client.transfer_with_memo(&a, &b, &1001, &memo(&t));
+    assert_eq!(t.client.balance(&b), 2001);
+}
+
+#[test]
+fn test_memo_emitted_alongside_transfer_event() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    let m = memo(&t);
+    t.client.transfer_with_memo(&a, &b, &500, &m);
+
+    let events = t.env.events().all();
+    let n = events.len();
+
+    // `transfer` segue o SEP-41: valor i128 puro
+    let (_, topics, data) = events.get(n - 2).unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&t.env);
+    let amount: i128 = data.into_val(&t.env);
+    assert_eq!(name, Symbol::new(&t.env, "transfer"));
+    assert_eq!(amount, 500);
+
+    // Memo vai em evento separado
+    let (_, topics, data) = events.get(n - 1).unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&t.env);
+    let emitted: TravelRuleMemo = data.into_val(&t.env);
+    assert_eq!(name, Symbol::new(&t.env, "tr_memo"));
+    assert_eq!(emitted, m);
+}
+
+#[test]
+fn test_transfer_from_requires_memo() {
+    let t = TestEnv::new();
+    let owner = t.create_compliant_user();
+    let spender = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&owner, &10_000);
+    t.client.approve(&owner, &spender, &5000);
+
+    t.client.set_travel_rule_threshold(&t.admin, &1000);
+
+    let res = t.client.try_transfer_from(&spender, &owner, &b, &2000);
+    assert_eq!(res, Err(Ok(BrazaError::TravelRuleMemoRequired)));
+
+    t.client
+        .transfer_from_with_memo(&spender, &owner, &b, &2000, &memo(&t));
+    assert_eq!(t.client.balance(&b), 2000);
+}
+
+#[test]
+fn test_held_transfer_keeps_memo() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&a, &10_000);
+
+    t.client.set_transfer_limits(
+        &t.admin,
+        &TransferLimits {
+            max_amount: 0,
+            hold_threshold: 1000,
+            auto_release_ledgers: 0,
+        },
+    );
+
+    let m = memo(&t);
+    t.client.transfer_with_memo(&a, &b, &3000, &m);
+    assert!(t.client.get_pending_transfer(&0).has_memo);
+    assert_eq!(t.client.get_pending_memo(&0), Some(m.clone()));
+
+    t.client.approve_pending_transfer(&t.admin, &0);
+    assert_eq!(t.client.get_pending_memo(&0), None);
+    assert_eq!(t.client.balance(&b), 3000);
+}
@@ -0,0 +1,149 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Ledger, vec, Address, Vec};
+
+use braza_token::types::{BrazaError, VestingClock, VestingCurve, VestingGrant, VestingTerms};
+
+const GRANT: i128 = 10_000_000;
+
+fn grant(beneficiary: &Address) -> VestingGrant {
+    VestingGrant {
+        beneficiary: beneficiary.clone(),
+        total_amount: GRANT,
+        revocable: true,
+        terms: VestingTerms {
+            clock: VestingClock::Ledger,
+            start: None,
+            cliff: 0,
+            duration: 100,
+            curve: VestingCurve::Linear,
+        },
+    }
+}
+
+fn advance(t: &TestEnv, ledgers: u32) {
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += ledgers;
+    });
+}
+
+#[test]
+fn test_archive_completed_schedule() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    let id = t
+        .client
+        .create_vesting(&beneficiary, &GRANT, &0, &100, &true);
+
+    let res = t.client.try_archive_vesting(&beneficiary, &id);
+    assert_eq!(res, Err(Ok(BrazaError::VestingStillActive)));
+
+    advance(&t, 100);
+    t.client.release_vested(&beneficiary, &id);
+
+    let global_before = t.client.get_global_vesting_usage().active_schedules;
+    let summary = t.client.archive_vesting(&beneficiary, &id);
+    assert_eq!(summary.schedule_id, id);
+    assert_eq!(summary.released_amount, GRANT);
+    assert!(!summary.revoked);
+
+    assert!(t
+        .client
+        .try_get_vesting_schedule(&beneficiary, &id)
+        .is_err());
+    assert_eq!(t.client.get_archived_vesting_count(&beneficiary), 1);
+    assert_eq!(
+        t.client.get_archived_vestings(&beneficiary, &0, &10).len(),
+        1
+    );
+    assert_eq!(t.client.get_active_vesting_count(&beneficiary), 0);
+    assert_eq!(
+        t.client.get_global_vesting_usage().active_schedules,
+        global_before - 1
+    );
+}
+
+#[test]
+fn test_archived_slot_is_reused() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    // Preenche todos os 50 slots
+    let mut grants: Vec<VestingGrant> = Vec::new(&t.env);
+    for _ in 0..50 {
+        grants.push_back(grant(&beneficiary));
+    }
+    t.client.create_vesting_batch(&t.admin, &grants);
+
+    advance(&t, 1_440);
+    let res = t
+        .client
+        .try_create_vesting(&beneficiary, &GRANT, &0, &100, &true);
+    assert_eq!(res, Err(Ok(BrazaError::MaxVestingSchedulesExceeded)));
+
+    // Revoga dois (pagando o vestido na hora) e arquiva em lote
+    t.client.set_vesting_revoke_payout(&t.admin, &true);
+    t.client.revoke_vesting(&beneficiary, &3);
+    t.client.revoke_vesting(&beneficiary, &7);
+    let archived = t.client.archive_completed_vestings(&beneficiary);
+    assert_eq!(archived.len(), 2);
+    assert_eq!(t.client.get_active_vesting_count(&beneficiary), 48);
+
+    // Novos grants reaproveitam os ids liberados
+    let ids = t.client.create_vesting_batch(
+        &t.admin,
+        &vec![&t.env, grant(&beneficiary), grant(&beneficiary)],
+    );
+    assert_eq!(ids, vec![&t.env, 3, 7]);
+    assert_eq!(t.client.get_all_vesting_schedules(&beneficiary).len(), 50);
+}
+
+#[test]
+fn test_transfer_frees_slot_without_growing_global_count() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let id = t.client.create_vesting(&a, &GRANT, &0, &100, &true);
+
+    let global_before = t.client.get_global_vesting_usage().active_schedules;
+    t.client.transfer_vesting(&a, &id, &b);
+    assert_eq!(
+        t.client.get_global_vesting_usage().active_schedules,
+        global_before
+    );
+    assert_eq!(t.client.get_active_vesting_count(&a), 0);
+}
+
+#[test]
+fn test_repeated_archival_keeps_one_entry_per_index() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    // Três rodadas de cria/conclui/arquiva reaproveitando o mesmo slot
+    for round in 0..3u32 {
+        let ids = t
+            .client
+            .create_vesting_batch(&t.admin, &vec![&t.env, grant(&beneficiary)]);
+        let id = ids.get(0).unwrap();
+        advance(&t, 100);
+        t.client.release_vested(&beneficiary, &id);
+        t.client.archive_vesting(&beneficiary, &id);
+        assert_eq!(t.client.get_archived_vesting_count(&beneficiary), round + 1);
+        advance(&t, 1_440);
+    }
+
+    let all = t.client.get_archived_vestings(&beneficiary, &0, &10);
+    assert_eq!(all.len(), 3);
+    assert!(all.iter().all(|s| s.released_amount == GRANT));
+
+    // Paginação
+    let page = t.client.get_archived_vestings(&beneficiary, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), all.get(1).unwrap());
+    assert_eq!(
+        t.client.get_archived_vestings(&beneficiary, &3, &10).len(),
+        0
+    );
+}
@@ -0,0 +1,175 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Ledger, vec, Address, Vec};
+
+use braza_token::types::{BrazaError, VestingClock, VestingCurve, VestingGrant, VestingTerms};
+
+const GRANT: i128 = 100_000_000;
+const FEE: i128 = 1_000_000; // VESTING_STORAGE_FEE
+
+fn grant(beneficiary: &Address, total_amount: i128) -> VestingGrant {
+    VestingGrant {
+        beneficiary: beneficiary.clone(),
+        total_amount,
+        revocable: true,
+        terms: VestingTerms {
+            clock: VestingClock::Ledger,
+            start: None,
+            cliff: 100,
+            duration: 1000,
+            curve: VestingCurve::Linear,
+        },
+    }
+}
+
+#[test]
+fn test_batch_creates_all_grants() {
+    let t = TestEnv::new();
+    let treasury = t.create_compliant_user();
+    t.client.set_vesting_funder(&t.admin, &treasury, &true);
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&treasury, &(3 * (GRANT + FEE)));
+
+    let grants = vec![&t.env, grant(&a, GRANT), grant(&b, GRANT), grant(&a, GRANT)];
+    let ids = t.client.create_vesting_batch(&treasury, &grants);
+
+    assert_eq!(ids, vec![&t.env, 1, 1, 2]);
+    assert_eq!(t.client.balance(&treasury), 0);
+    assert_eq!(t.client.get_locked_balance(), 3 * GRANT);
+    assert_eq!(t.client.get_all_vesting_schedules(&a).len(), 2);
+    assert_eq!(t.client.get_vesting_schedule(&b, &1).funder, treasury);
+}
+
+#[test]
+fn test_batch_requires_listed_funder() {
+    let t = TestEnv::new();
+    let stranger = t.create_compliant_user();
+    let a = t.create_compliant_user();
+    t.client.mint(&stranger, &(GRANT + FEE));
+
+    let res = t
+        .client
+        .try_create_vesting_batch(&stranger, &vec![&t.env, grant(&a, GRANT)]);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+}
+
+#[test]
+fn test_batch_insufficient_balance_is_atomic() {
+    let t = TestEnv::new();
+    let treasury = t.create_compliant_user();
+    t.client.set_vesting_funder(&t.admin, &treasury, &true);
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    t.client.mint(&treasury, &(2 * GRANT));
+
+    let grants = vec![&t.env, grant(&a, GRANT), grant(&b, 2 * GRANT)];
+    let res = t.client.try_create_vesting_batch(&treasury, &grants);
+    assert_eq!(res, Err(Ok(BrazaError::InsufficientBalance)));
+
+    assert_eq!(t.client.balance(&treasury), 2 * GRANT);
+    assert_eq!(t.client.get_all_vesting_schedules(&a).len(), 0);
+}
+
+#[test]
+fn test_batch_respects_per_beneficiary_limit() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+
+    let mut grants: Vec<VestingGrant> = Vec::new(&t.env);
+    for _ in 0..51 {
+        grants.push_back(grant(&a, 10_000_000));
+    }
+    let res = t.client.try_create_vesting_batch(&t.admin, &grants);
+    assert_eq!(res, Err(Ok(BrazaError::MaxVestingSchedulesExceeded)));
+
+    let res = t
+        .client
+        .try_create_vesting_batch(&t.admin, &Vec::new(&t.env));
+    assert_eq!(res, Err(Ok(BrazaError::InvalidVestingParams)));
+}
+
+// ============================================================================
+// COOLDOWN E TAXA DE STORAGE
+// ============================================================================
+
+#[test]
+fn test_vesting_fee_collected_into_pool() {
+    let t = TestEnv::new();
+    let treasury = t.create_compliant_user();
+    t.client.set_vesting_funder(&t.admin, &treasury, &true);
+    let a = t.create_compliant_user();
+    t.client.mint(&treasury, &(GRANT + FEE));
+
+    t.client
+        .create_vesting_funded(&treasury, &a, &GRANT, &100, &1000, &true);
+    assert_eq!(t.client.balance(&treasury), 0);
+    assert_eq!(t.client.get_locked_balance(), GRANT);
+    assert_eq!(t.client.get_vesting_fee_pool(), FEE);
+    let usage = t.client.get_global_vesting_usage();
+    assert_eq!(usage.active_schedules, 1);
+    assert_eq!(usage.max_global_schedules, 10_000);
+
+    // Sem saldo para a taxa não cria
+    let b = t.create_compliant_user();
+    t.client.mint(&treasury, &GRANT);
+    let res = t
+        .client
+        .try_create_vesting_funded(&treasury, &b, &GRANT, &100, &1000, &true);
+    assert_eq!(res, Err(Ok(BrazaError::InsufficientBalance)));
+}
+
+#[test]
+fn test_vesting_cooldown_per_beneficiary() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+
+    t.client.create_vesting(&a, &GRANT, &100, &1000, &true);
+    assert_eq!(t.client.get_vesting_cooldown(&t.admin, &a), 1_440);
+
+    let res = t.client.try_create_vesting(&a, &GRANT, &100, &1000, &true);
+    assert_eq!(res, Err(Ok(BrazaError::VestingCooldownActive)));
+    let res = t
+        .client
+        .try_create_vesting_batch(&t.admin, &vec![&t.env, grant(&a, GRANT)]);
+    assert_eq!(res, Err(Ok(BrazaError::VestingCooldownActive)));
+
+    // Outro beneficiário não é afetado
+    t.client.create_vesting(&b, &GRANT, &100, &1000, &true);
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 1_440;
+    });
+    assert_eq!(t.client.get_vesting_cooldown(&t.admin, &a), 0);
+    t.client.create_vesting(&a, &GRANT, &100, &1000, &true);
+}
+
+#[test]
+fn test_fee_pool_withdraw_and_burn() {
+    let t = TestEnv::new();
+    let treasury = t.create_compliant_user();
+    t.client.set_vesting_funder(&t.admin, &treasury, &true);
+    for _ in 0..3 {
+        let user = t.create_compliant_user();
+        t.client.create_vesting(&user, &GRANT, &100, &1000, &true);
+    }
+    assert_eq!(t.client.get_vesting_fee_pool(), 3 * FEE);
+
+    t.client.withdraw_vesting_fees(&t.admin, &treasury, &FEE);
+    assert_eq!(t.client.balance(&treasury), FEE);
+
+    let supply = t.client.total_supply();
+    t.client.burn_vesting_fees(&t.admin, &FEE);
+    assert_eq!(t.client.total_supply(), supply - FEE);
+    assert_eq!(t.client.get_vesting_fee_pool(), FEE);
+
+    let res = t.client.try_burn_vesting_fees(&t.admin, &(2 * FEE));
+    assert_eq!(res, Err(Ok(BrazaError::InsufficientBalance)));
+    let res = t
+        .client
+        .try_withdraw_vesting_fees(&treasury, &treasury, &FEE);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+}
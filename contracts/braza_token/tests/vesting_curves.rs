@@ -0,0 +1,167 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{testutils::Ledger, vec};
+
+use braza_token::types::{BrazaError, Milestone, UnlockPoint, VestingCurve};
+
+const GRANT: i128 = 100_000_000;
+
+fn advance(t: &TestEnv, ledgers: u32) {
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += ledgers;
+    });
+}
+
+#[test]
+fn test_periodic_curve_unlocks_in_steps() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    // 4 degraus de 250 ledgers
+    let id = t.client.create_vesting_with_curve(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &0,
+        &1000,
+        &true,
+        &VestingCurve::Periodic(250),
+    );
+
+    advance(&t, 249);
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), 0);
+
+    advance(&t, 1);
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), GRANT / 4);
+
+    advance(&t, 400);
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), GRANT / 2);
+
+    advance(&t, 350);
+    t.client.release_vested(&beneficiary, &id);
+    assert_eq!(t.client.balance(&beneficiary), GRANT);
+}
+
+#[test]
+fn test_custom_unlock_table() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    let start = t.env.ledger().sequence();
+
+    let table = vec![
+        &t.env,
+        UnlockPoint {
+            ledger: start + 100,
+            cumulative_amount: 10_000_000,
+        },
+        UnlockPoint {
+            ledger: start + 500,
+            cumulative_amount: GRANT,
+        },
+    ];
+    let id = t.client.create_vesting_with_curve(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &0,
+        &1000,
+        &true,
+        &VestingCurve::Custom(table),
+    );
+
+    advance(&t, 100);
+    t.client.release_vested(&beneficiary, &id);
+    assert_eq!(t.client.balance(&beneficiary), 10_000_000);
+
+    advance(&t, 399);
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), 0);
+
+    advance(&t, 1);
+    assert_eq!(
+        t.client.get_releasable_amount(&beneficiary, &id),
+        GRANT - 10_000_000
+    );
+}
+
+#[test]
+fn test_invalid_custom_table_rejected() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    // Último ponto não soma o total
+    let table = vec![
+        &t.env,
+        UnlockPoint {
+            ledger: 10,
+            cumulative_amount: 500,
+        },
+    ];
+    let res = t.client.try_create_vesting_with_curve(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &0,
+        &1000,
+        &true,
+        &VestingCurve::Custom(table),
+    );
+    assert_eq!(res, Err(Ok(BrazaError::InvalidVestingParams)));
+
+    let res = t.client.try_create_vesting_with_curve(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &0,
+        &1000,
+        &true,
+        &VestingCurve::Periodic(0),
+    );
+    assert_eq!(res, Err(Ok(BrazaError::InvalidVestingParams)));
+}
+
+#[test]
+fn test_milestones_unlock_on_attestation() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    let milestones = vec![
+        &t.env,
+        Milestone {
+            amount: 30_000_000,
+            attested: false,
+        },
+        Milestone {
+            amount: 70_000_000,
+            attested: false,
+        },
+    ];
+    let id = t.client.create_vesting_with_curve(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &0,
+        &1000,
+        &true,
+        &VestingCurve::Milestones(milestones),
+    );
+
+    // Passar do tempo não libera nada sem atestação
+    advance(&t, 2000);
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), 0);
+
+    assert_eq!(
+        t.client.attest_vesting_milestone(&beneficiary, &id, &1),
+        70_000_000
+    );
+    t.client.release_vested(&beneficiary, &id);
+    assert_eq!(t.client.balance(&beneficiary), 70_000_000);
+
+    let res = t.client.try_attest_vesting_milestone(&beneficiary, &id, &1);
+    assert_eq!(res, Err(Ok(BrazaError::VestingAlreadyReleased)));
+
+    t.client.attest_vesting_milestone(&beneficiary, &id, &0);
+    t.client.release_vested(&beneficiary, &id);
+    assert_eq!(t.client.balance(&beneficiary), GRANT);
+}
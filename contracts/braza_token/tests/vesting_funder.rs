@@ -0,0 +1,131 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::testutils::Ledger;
+
+use braza_token::types::BrazaError;
+
+const GRANT: i128 = 100_000_000;
+const FEE: i128 = 1_000_000; // VESTING_STORAGE_FEE
+
+#[test]
+fn test_funder_creates_vesting_from_own_balance() {
+    let t = TestEnv::new();
+    let treasury = t.create_compliant_user();
+    t.client.set_vesting_funder(&t.admin, &treasury, &true);
+    let beneficiary = t.create_compliant_user();
+    t.client.mint(&treasury, &(GRANT + FEE));
+
+    let admin_before = t.client.balance(&t.admin);
+    let id = t
+        .client
+        .create_vesting_funded(&treasury, &beneficiary, &GRANT, &100, &1000, &true);
+
+    assert_eq!(t.client.balance(&treasury), 0);
+    assert_eq!(t.client.balance(&t.admin), admin_before);
+    assert_eq!(t.client.get_locked_balance(), GRANT);
+
+    let schedule = t.client.get_vesting_schedule(&beneficiary, &id);
+    assert_eq!(schedule.funder, treasury);
+}
+
+#[test]
+fn test_revoke_returns_unvested_to_funder() {
+    let t = TestEnv::new();
+    let treasury = t.create_compliant_user();
+    t.client.set_vesting_funder(&t.admin, &treasury, &true);
+    let beneficiary = t.create_compliant_user();
+    t.client.mint(&treasury, &(GRANT + FEE));
+
+    let admin_before = t.client.balance(&t.admin);
+    let id = t
+        .client
+        .create_vesting_funded(&treasury, &beneficiary, &GRANT, &100, &1000, &true);
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 500;
+    });
+
+    let unvested = t.client.revoke_vesting(&beneficiary, &id);
+    assert_eq!(unvested, GRANT / 2);
+    assert_eq!(t.client.balance(&treasury), GRANT / 2);
+    assert_eq!(t.client.balance(&t.admin), admin_before);
+}
+
+#[test]
+fn test_funder_needs_balance() {
+    let t = TestEnv::new();
+    let treasury = t.create_compliant_user();
+    t.client.set_vesting_funder(&t.admin, &treasury, &true);
+    let beneficiary = t.create_compliant_user();
+
+    let res =
+        t.client
+            .try_create_vesting_funded(&treasury, &beneficiary, &GRANT, &100, &1000, &true);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_admin_vesting_records_admin_as_funder() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    let id = t
+        .client
+        .create_vesting(&beneficiary, &GRANT, &100, &1000, &true);
+    assert_eq!(
+        t.client.get_vesting_schedule(&beneficiary, &id).funder,
+        t.admin
+    );
+}
+
+#[test]
+fn test_unlisted_funder_cannot_create_vesting() {
+    let t = TestEnv::new();
+    let attacker = t.create_compliant_user();
+    let victim = t.create_compliant_user();
+    t.client.mint(&attacker, &(GRANT + FEE));
+
+    // Grant-poeira não revogável com duração máxima lotaria os slots da vítima
+    let res =
+        t.client
+            .try_create_vesting_funded(&attacker, &victim, &10_000_000, &0, &u32::MAX, &false);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+    assert_eq!(t.client.get_all_vesting_schedules(&victim).len(), 0);
+    assert_eq!(t.client.balance(&attacker), GRANT + FEE);
+
+    // Removido da allowlist também perde o acesso
+    t.client.set_vesting_funder(&t.admin, &attacker, &true);
+    assert!(t.client.is_vesting_funder(&attacker));
+    t.client.set_vesting_funder(&t.admin, &attacker, &false);
+    let res = t
+        .client
+        .try_create_vesting_funded(&attacker, &victim, &GRANT, &100, &1000, &true);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+}
+
+#[test]
+fn test_funder_cooldown_does_not_block_admin() {
+    let t = TestEnv::new();
+    let treasury = t.create_compliant_user();
+    let beneficiary = t.create_compliant_user();
+    t.client.set_vesting_funder(&t.admin, &treasury, &true);
+    t.client.mint(&treasury, &(2 * (GRANT + FEE)));
+
+    t.client
+        .create_vesting_funded(&treasury, &beneficiary, &GRANT, &100, &1000, &true);
+    assert_eq!(
+        t.client.get_vesting_cooldown(&treasury, &beneficiary),
+        1_440
+    );
+    assert_eq!(t.client.get_vesting_cooldown(&t.admin, &beneficiary), 0);
+
+    // Cooldown é por financiador: o grant do admin segue liberado
+    t.client
+        .create_vesting(&beneficiary, &GRANT, &100, &1000, &true);
+    let res =
+        t.client
+            .try_create_vesting_funded(&treasury, &beneficiary, &GRANT, &100, &1000, &true);
+    assert_eq!(res, Err(Ok(BrazaError::VestingCooldownActive)));
+}
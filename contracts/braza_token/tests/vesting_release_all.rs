@@ -0,0 +1,79 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger},
+    vec, Address, IntoVal, Symbol,
+};
+
+use braza_token::types::{BrazaError, VestingClock, VestingCurve, VestingGrant, VestingTerms};
+
+const GRANT: i128 = 100_000_000;
+
+fn grant(beneficiary: &Address, cliff: u64, duration: u64) -> VestingGrant {
+    VestingGrant {
+        beneficiary: beneficiary.clone(),
+        total_amount: GRANT,
+        revocable: true,
+        terms: VestingTerms {
+            clock: VestingClock::Ledger,
+            start: None,
+            cliff,
+            duration,
+            curve: VestingCurve::Linear,
+        },
+    }
+}
+
+#[test]
+fn test_release_all_across_schedules() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    // O terceiro ainda estará no cliff e não contribui
+    let grants = vec![
+        &t.env,
+        grant(&beneficiary, 0, 1000),
+        grant(&beneficiary, 0, 2000),
+        grant(&beneficiary, 900, 1000),
+    ];
+    t.client.create_vesting_batch(&t.admin, &grants);
+    assert_eq!(t.client.get_all_vesting_schedules(&beneficiary).len(), 3);
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 500;
+    });
+
+    let expected = GRANT / 2 + GRANT / 4;
+    assert_eq!(t.client.get_total_releasable(&beneficiary), expected);
+
+    assert_eq!(t.client.release_all_vested(&beneficiary), expected);
+    assert_eq!(t.client.balance(&beneficiary), expected);
+    assert_eq!(t.client.get_locked_balance(), 3 * GRANT - expected);
+    assert_eq!(t.client.get_total_releasable(&beneficiary), 0);
+
+    // Evento agregado: total e número de schedules liberados
+    let events = t.env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    let topic: Symbol = topics.get(0).unwrap().into_val(&t.env);
+    assert_eq!(topic, symbol_short!("v_relall"));
+    let (total, count): (i128, u32) = data.into_val(&t.env);
+    assert_eq!(total, expected);
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_release_all_nothing_to_release() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    let res = t.client.try_release_all_vested(&beneficiary);
+    assert_eq!(res, Err(Ok(BrazaError::NoTokensToRelease)));
+
+    t.client
+        .create_vesting(&beneficiary, &GRANT, &100, &1000, &true);
+    let res = t.client.try_release_all_vested(&beneficiary);
+    assert_eq!(res, Err(Ok(BrazaError::NoTokensToRelease)));
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::testutils::Ledger;
+
+use braza_token::types::BrazaError;
+
+const GRANT: i128 = 100_000_000;
+
+fn advance(t: &TestEnv, ledgers: u32) {
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += ledgers;
+    });
+}
+
+#[test]
+fn test_revoke_freezes_vested_as_claimable() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    let id = t
+        .client
+        .create_vesting(&beneficiary, &GRANT, &0, &1000, &true);
+
+    advance(&t, 200);
+    t.client.release_vested(&beneficiary, &id);
+    advance(&t, 300);
+
+    // 50% vestido, 20% já liberado → 30% congelado e sacável
+    let unvested = t.client.revoke_vesting(&beneficiary, &id);
+    assert_eq!(unvested, GRANT / 2);
+    assert_eq!(t.client.get_locked_balance(), GRANT * 3 / 10);
+
+    // Total original preservado; o vestido congelado fica em campo próprio
+    let schedule = t.client.get_vesting_schedule(&beneficiary, &id);
+    assert_eq!(schedule.total_amount, GRANT);
+    assert_eq!(schedule.revoked_vested, GRANT / 2);
+    assert_eq!(
+        t.client.get_releasable_amount(&beneficiary, &id),
+        GRANT * 3 / 10
+    );
+
+    // Tempo não aumenta o vestido de um schedule revogado
+    advance(&t, 500);
+    assert_eq!(t.client.release_vested(&beneficiary, &id), GRANT * 3 / 10);
+    assert_eq!(t.client.balance(&beneficiary), GRANT / 2);
+    assert_eq!(t.client.get_locked_balance(), 0);
+
+    let res = t.client.try_release_vested(&beneficiary, &id);
+    assert_eq!(res, Err(Ok(BrazaError::NoTokensToRelease)));
+}
+
+#[test]
+fn test_revoke_auto_release_pays_beneficiary() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    let id = t
+        .client
+        .create_vesting(&beneficiary, &GRANT, &0, &1000, &true);
+
+    t.client.set_vesting_revoke_payout(&t.admin, &true);
+    assert!(t.client.get_vesting_revoke_payout());
+
+    advance(&t, 250);
+    t.client.revoke_vesting(&beneficiary, &id);
+
+    assert_eq!(t.client.balance(&beneficiary), GRANT / 4);
+    assert_eq!(t.client.get_locked_balance(), 0);
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), 0);
+
+    // Schedule quitado pode ser arquivado
+    let summary = t.client.archive_vesting(&beneficiary, &id);
+    assert!(summary.revoked);
+    assert_eq!(summary.total_amount, GRANT);
+    assert_eq!(summary.released_amount, GRANT / 4);
+}
+
+#[test]
+fn test_locked_balance_reconciles_across_schedules() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let id_a = t.client.create_vesting(&a, &GRANT, &0, &1000, &true);
+    let id_b = t.client.create_vesting(&b, &GRANT, &0, &1000, &true);
+
+    advance(&t, 400);
+    t.client.revoke_vesting(&a, &id_a);
+    t.client.release_vested(&b, &id_b);
+
+    // locked = soma de (devido - liberado) dos schedules restantes
+    let outstanding: i128 = [(&a, id_a), (&b, id_b)]
+        .iter()
+        .map(|(who, id)| {
+            let s = t.client.get_vesting_schedule(who, id);
+            let owed = if s.revoked {
+                s.revoked_vested
+            } else {
+                s.total_amount
+            };
+            owed - s.released_amount
+        })
+        .sum();
+    assert_eq!(t.client.get_locked_balance(), outstanding);
+}
@@ -0,0 +1,135 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::testutils::Ledger;
+
+use braza_token::types::{
+    BrazaError, VestingClock, VestingCurve, VestingStartLimits, VestingTerms,
+};
+
+const GRANT: i128 = 100_000_000;
+const DAY: u64 = 86_400;
+const NOW: u64 = 1_700_000_000;
+
+fn terms(clock: VestingClock, start: u64, cliff: u64, duration: u64) -> VestingTerms {
+    VestingTerms {
+        clock,
+        start: Some(start),
+        cliff,
+        duration,
+        curve: VestingCurve::Linear,
+    }
+}
+
+fn setup_with_limits(t: &TestEnv) {
+    t.env.ledger().with_mut(|info| {
+        info.timestamp = NOW;
+    });
+    t.client.set_vesting_start_limits(
+        &t.admin,
+        &VestingStartLimits {
+            max_backdate_seconds: 365 * DAY,
+            max_future_seconds: 30 * DAY,
+        },
+    );
+}
+
+#[test]
+fn test_custom_start_disabled_by_default() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    t.env.ledger().with_mut(|info| {
+        info.timestamp = NOW;
+    });
+
+    let res = t.client.try_create_vesting_with_terms(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &true,
+        &terms(VestingClock::Timestamp, NOW - DAY, 0, 10 * DAY),
+    );
+    assert_eq!(res, Err(Ok(BrazaError::VestingStartOutOfRange)));
+}
+
+#[test]
+fn test_backdated_grant_vests_immediately() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    setup_with_limits(&t);
+
+    // Contrato assinado há 100 dias, vesting de 200 dias
+    let id = t.client.create_vesting_with_terms(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &true,
+        &terms(
+            VestingClock::Timestamp,
+            NOW - 100 * DAY,
+            30 * DAY,
+            200 * DAY,
+        ),
+    );
+
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), GRANT / 2);
+    let tl = t.client.get_vesting_timeline(&beneficiary, &id);
+    assert_eq!(tl.start_time, NOW - 100 * DAY);
+}
+
+#[test]
+fn test_future_start_in_ledgers() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    setup_with_limits(&t);
+
+    // TGE daqui a 1000 ledgers
+    let tge = t.env.ledger().sequence() + 1000;
+    let id = t.client.create_vesting_with_terms(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &true,
+        &terms(VestingClock::Ledger, tge as u64, 0, 1000),
+    );
+    let schedule = t.client.get_vesting_schedule(&beneficiary, &id);
+    assert_eq!(schedule.start_ledger, tge);
+    assert_eq!(schedule.start_time, NOW + 5000);
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 1000;
+    });
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), 0);
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 500;
+    });
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), GRANT / 2);
+}
+
+#[test]
+fn test_start_outside_window_rejected() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    setup_with_limits(&t);
+
+    let res = t.client.try_create_vesting_with_terms(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &true,
+        &terms(VestingClock::Timestamp, NOW + 31 * DAY, 0, 10 * DAY),
+    );
+    assert_eq!(res, Err(Ok(BrazaError::VestingStartOutOfRange)));
+
+    let stranger = t.create_compliant_user();
+    let res = t.client.try_set_vesting_start_limits(
+        &stranger,
+        &VestingStartLimits {
+            max_backdate_seconds: 0,
+            max_future_seconds: 0,
+        },
+    );
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+}
@@ -0,0 +1,108 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::testutils::Ledger;
+
+use braza_token::types::{BrazaError, VestingClock, VestingCurve, VestingTerms};
+
+const GRANT: i128 = 100_000_000;
+const DAY: u64 = 86_400;
+
+fn timed_terms(cliff: u64, duration: u64) -> VestingTerms {
+    VestingTerms {
+        clock: VestingClock::Timestamp,
+        start: None,
+        cliff,
+        duration,
+        curve: VestingCurve::Linear,
+    }
+}
+
+#[test]
+fn test_timestamp_vesting_follows_wall_clock() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    t.env.ledger().with_mut(|info| {
+        info.timestamp = 1_700_000_000;
+    });
+
+    let id = t.client.create_vesting_with_terms(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &true,
+        &timed_terms(10 * DAY, 100 * DAY),
+    );
+
+    // Muitos ledgers sem avanço de tempo não liberam nada
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 1_000;
+    });
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), 0);
+
+    // Metade do período em tempo, ledger parado
+    t.env.ledger().with_mut(|info| {
+        info.timestamp += 50 * DAY;
+    });
+    assert_eq!(t.client.get_releasable_amount(&beneficiary, &id), GRANT / 2);
+
+    t.env.ledger().with_mut(|info| {
+        info.timestamp += 50 * DAY;
+    });
+    t.client.release_vested(&beneficiary, &id);
+    assert_eq!(t.client.balance(&beneficiary), GRANT);
+}
+
+#[test]
+fn test_timeline_returns_both_representations() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    t.env.ledger().with_mut(|info| {
+        info.timestamp = 1_700_000_000;
+    });
+    let start_ledger = t.env.ledger().sequence();
+
+    let id = t.client.create_vesting_with_terms(
+        &t.admin,
+        &beneficiary,
+        &GRANT,
+        &true,
+        &timed_terms(DAY, 10 * DAY),
+    );
+
+    let tl = t.client.get_vesting_timeline(&beneficiary, &id);
+    assert_eq!(tl.clock, VestingClock::Timestamp);
+    assert_eq!(tl.start_time, 1_700_000_000);
+    assert_eq!(tl.cliff_time, 1_700_000_000 + DAY);
+    assert_eq!(tl.end_time, 1_700_000_000 + 10 * DAY);
+    // Estimativa em ledgers de 5 segundos
+    assert_eq!(tl.start_ledger, start_ledger);
+    assert_eq!(tl.cliff_ledger, start_ledger + 17_280);
+    assert_eq!(tl.end_ledger, start_ledger + 172_800);
+
+    // Schedules por ledger também expõem a estimativa em tempo
+    let other = t.create_compliant_user();
+    let id = t.client.create_vesting(&other, &GRANT, &100, &1000, &true);
+    let tl = t.client.get_vesting_timeline(&other, &id);
+    assert_eq!(tl.clock, VestingClock::Ledger);
+    assert_eq!(tl.end_time, 1_700_000_000 + 5000);
+}
+
+#[test]
+fn test_ledger_terms_must_fit_u32() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+
+    let terms = VestingTerms {
+        clock: VestingClock::Ledger,
+        start: None,
+        cliff: 0,
+        duration: u32::MAX as u64 + 1,
+        curve: VestingCurve::Linear,
+    };
+    let res = t
+        .client
+        .try_create_vesting_with_terms(&t.admin, &beneficiary, &GRANT, &true, &terms);
+    assert_eq!(res, Err(Ok(BrazaError::InvalidVestingParams)));
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::testutils::Ledger;
+
+use braza_token::types::BrazaError;
+
+const GRANT: i128 = 100_000_000;
+
+fn advance(t: &TestEnv, ledgers: u32) {
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += ledgers;
+    });
+}
+
+#[test]
+fn test_transfer_vesting_moves_schedule() {
+    let t = TestEnv::new();
+    let old_key = t.create_compliant_user();
+    let new_key = t.create_compliant_user();
+
+    let id = t.client.create_vesting(&old_key, &GRANT, &0, &1000, &true);
+    advance(&t, 250);
+    t.client.release_vested(&old_key, &id);
+
+    let new_id = t.client.transfer_vesting(&old_key, &id, &new_key);
+    assert!(t.client.try_get_vesting_schedule(&old_key, &id).is_err());
+
+    let schedule = t.client.get_vesting_schedule(&new_key, &new_id);
+    assert_eq!(schedule.beneficiary, new_key);
+    assert_eq!(schedule.released_amount, GRANT / 4);
+
+    // Progresso do vesting é preservado
+    advance(&t, 250);
+    assert_eq!(t.client.release_vested(&new_key, &new_id), GRANT / 4);
+}
+
+#[test]
+fn test_transfer_vesting_admin_consent() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let id = t.client.create_vesting(&a, &GRANT, &0, &1000, &true);
+
+    assert!(!t.client.get_vesting_transfer_consent());
+    t.client.set_vesting_transfer_consent(&t.admin, &true);
+
+    t.client.transfer_vesting(&a, &id, &b);
+    let signers: std::vec::Vec<_> = t.env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert!(signers.contains(&a));
+    assert!(signers.contains(&b));
+    assert!(signers.contains(&t.admin));
+}
+
+#[test]
+fn test_transfer_vesting_rejects_blacklisted_target() {
+    let t = TestEnv::new();
+    let a = t.create_compliant_user();
+    let b = t.create_compliant_user();
+    let id = t.client.create_vesting(&a, &GRANT, &0, &1000, &true);
+
+    t.client.set_blacklisted(&b, &true);
+    let res = t.client.try_transfer_vesting(&a, &id, &b);
+    assert_eq!(res, Err(Ok(BrazaError::Blacklisted)));
+}
+
+#[test]
+fn test_delegated_claimer_releases_to_beneficiary() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    let custodian = t.create_compliant_user();
+    let stranger = t.create_compliant_user();
+
+    let id = t
+        .client
+        .create_vesting(&beneficiary, &GRANT, &0, &1000, &true);
+    advance(&t, 500);
+
+    let res = t
+        .client
+        .try_release_vested_for(&custodian, &beneficiary, &id);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    t.client
+        .set_vesting_claimer(&beneficiary, &id, &Some(custodian.clone()));
+    assert_eq!(
+        t.client.get_vesting_claimer(&beneficiary, &id),
+        Some(custodian.clone())
+    );
+
+    let res = t
+        .client
+        .try_release_vested_for(&stranger, &beneficiary, &id);
+    assert_eq!(res, Err(Ok(BrazaError::Unauthorized)));
+
+    t.client.release_vested_for(&custodian, &beneficiary, &id);
+    assert_eq!(t.client.balance(&beneficiary), GRANT / 2);
+    assert_eq!(t.client.balance(&custodian), 0);
+}
@@ -0,0 +1,65 @@
+#![cfg(test)]
+#![cfg(not(tarpaulin_include))]
+mod setup;
+use setup::TestEnv;
+use soroban_sdk::testutils::Ledger;
+
+use braza_token::types::BrazaError;
+
+const GRANT: i128 = 100_000_000;
+
+#[test]
+fn test_voting_power_includes_eligible_vesting() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    t.client.mint(&beneficiary, &5_000);
+
+    let id = t
+        .client
+        .create_vesting(&beneficiary, &GRANT, &0, &1000, &true);
+
+    // Sem flag: apenas saldo livre
+    assert_eq!(t.client.voting_power(&beneficiary), 5_000);
+
+    t.client.set_vesting_voting_weight(&beneficiary, &id, &true);
+    assert!(
+        t.client
+            .get_vesting_schedule(&beneficiary, &id)
+            .voting_weight
+    );
+    assert_eq!(t.client.voting_power(&beneficiary), 5_000 + GRANT);
+
+    // Liberar move peso do vesting para o saldo sem alterar o total
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 400;
+    });
+    t.client.release_vested(&beneficiary, &id);
+    assert_eq!(t.client.voting_power(&beneficiary), 5_000 + GRANT);
+
+    t.client
+        .set_vesting_voting_weight(&beneficiary, &id, &false);
+    assert_eq!(t.client.voting_power(&beneficiary), 5_000 + GRANT * 4 / 10);
+}
+
+#[test]
+fn test_voting_weight_follows_revocation() {
+    let t = TestEnv::new();
+    let beneficiary = t.create_compliant_user();
+    let id = t
+        .client
+        .create_vesting(&beneficiary, &GRANT, &0, &1000, &true);
+    t.client.set_vesting_voting_weight(&beneficiary, &id, &true);
+
+    t.env.ledger().with_mut(|info| {
+        info.sequence_number += 250;
+    });
+    t.client.revoke_vesting(&beneficiary, &id);
+
+    // Só o vestido congelado continua contando
+    assert_eq!(t.client.voting_power(&beneficiary), GRANT / 4);
+
+    let res = t
+        .client
+        .try_set_vesting_voting_weight(&beneficiary, &99, &true);
+    assert_eq!(res, Err(Ok(BrazaError::VestingNotFound)));
+}